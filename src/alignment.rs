@@ -1,8 +1,46 @@
-use crate::{AlignCenterLeft, AlignCenterRight, AlignLeft, AlignRight, Pad, Width};
-use core::fmt::{Display, Error, Formatter};
+use crate::{
+    AlignCenterLeft, AlignCenterRight, AlignLeft, AlignRight, JustifyBiasLeft, JustifyBiasRight,
+    Pad, ParseAlignmentError, Width,
+};
+use core::{
+    fmt::{Display, Error, Formatter},
+    str::FromStr,
+};
 
 /// Where the place the pad blocks.
+///
+/// Every variant has a stable name (see [`name`](Alignment::name)),
+/// which is used by the [`Display`] and [`FromStr`] implementations,
+/// as well as by the `Serialize` and `Deserialize` implementations
+/// _(requires `serde` feature)_.
+///
+/// It covers the built-in pads that take no parameter and apply to any [`Width`].
+/// [`AlignRatio`](crate::AlignRatio), [`AlignDecimal`](crate::AlignDecimal) and [`Margin`](crate::Margin)
+/// take parameters that a name cannot carry, and [`JustifyBetween`](crate::JustifyBetween) only pads pairs,
+/// so they are out of scope and are to be used directly.
+///
+/// More variants may be added without a major version bump,
+/// so a `match` on this enum outside the crate needs a wildcard arm.
+///
+/// **Example:** Parse an alignment from a string
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{Alignment, PaddedValue, PanicOnExcess};
+/// let alignment: Alignment = "center-right".parse().unwrap();
+/// assert_eq!(alignment, Alignment::CenterRight);
+/// assert_eq!(alignment.to_string(), "center-right");
+/// let padded_value = PaddedValue {
+///     pad: alignment,
+///     value: "abcd",
+///     pad_block: '-',
+///     total_width: 9,
+///     handle_excess: PanicOnExcess,
+/// };
+/// assert_eq!(padded_value.to_string(), "---abcd--");
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Alignment {
     /// Pad to the right, content to the left.
     ///
@@ -105,9 +143,95 @@ pub enum Alignment {
     /// # #[cfg(not(feature = "std"))] fn main() {}
     /// ```
     CenterRight,
+
+    /// Distribute the pad across the gaps between words,
+    /// give the remainder blocks to the leftmost gaps.
+    ///
+    /// **Example:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{Alignment::JustifyBiasLeft, PaddedValue, PanicOnExcess};
+    /// let padded_value = PaddedValue {
+    ///     pad: JustifyBiasLeft,
+    ///     value: "a b c",
+    ///     pad_block: '-',
+    ///     total_width: 8,
+    ///     handle_excess: PanicOnExcess,
+    /// };
+    /// assert_eq!(padded_value.to_string(), "a --b -c");
+    /// ```
+    JustifyBiasLeft,
+
+    /// Distribute the pad across the gaps between words,
+    /// give the remainder blocks to the rightmost gaps.
+    ///
+    /// **Example:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{Alignment::JustifyBiasRight, PaddedValue, PanicOnExcess};
+    /// let padded_value = PaddedValue {
+    ///     pad: JustifyBiasRight,
+    ///     value: "a b c",
+    ///     pad_block: '-',
+    ///     total_width: 8,
+    ///     handle_excess: PanicOnExcess,
+    /// };
+    /// assert_eq!(padded_value.to_string(), "a -b --c");
+    /// ```
+    JustifyBiasRight,
 }
 
-impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for Alignment {
+impl Alignment {
+    /// All variants, in the order of declaration.
+    pub const ALL: [Alignment; 6] = [
+        Alignment::Left,
+        Alignment::Right,
+        Alignment::CenterLeft,
+        Alignment::CenterRight,
+        Alignment::JustifyBiasLeft,
+        Alignment::JustifyBiasRight,
+    ];
+
+    /// Stable name of the variant.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Alignment::Left => "left",
+            Alignment::Right => "right",
+            Alignment::CenterLeft => "center-left",
+            Alignment::CenterRight => "center-right",
+            Alignment::JustifyBiasLeft => "justify-bias-left",
+            Alignment::JustifyBiasRight => "justify-bias-right",
+        }
+    }
+}
+
+impl Display for Alignment {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(formatter, "{}", self.name())
+    }
+}
+
+impl FromStr for Alignment {
+    type Err = ParseAlignmentError;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Alignment::ALL
+            .iter()
+            .copied()
+            .find(|alignment| alignment.name() == text)
+            .ok_or(ParseAlignmentError)
+    }
+}
+
+impl Default for Alignment {
+    /// Pad to the right, content to the left.
+    fn default() -> Self {
+        Alignment::Left
+    }
+}
+
+impl<Value: Width, PadBlock: crate::PadBlock> Pad<Value, PadBlock> for Alignment {
     fn fmt(
         &self,
         formatter: &mut Formatter<'_>,
@@ -115,17 +239,64 @@ impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for Alignment {
         pad_block: &PadBlock,
         pad_width: usize,
     ) -> Result<(), Error> {
-        use Alignment::*;
         macro_rules! call {
-            ($name:ident) => {
-                $name.fmt(formatter, value, pad_block, pad_width)
+            ($pad:expr) => {
+                Pad::<Value, PadBlock>::fmt(&$pad, formatter, value, pad_block, pad_width)
             };
         }
         match *self {
-            Left => call!(AlignLeft),
-            Right => call!(AlignRight),
-            CenterLeft => call!(AlignCenterLeft),
-            CenterRight => call!(AlignCenterRight),
+            Alignment::Left => call!(AlignLeft),
+            Alignment::Right => call!(AlignRight),
+            Alignment::CenterLeft => call!(AlignCenterLeft),
+            Alignment::CenterRight => call!(AlignCenterRight),
+            Alignment::JustifyBiasLeft => call!(JustifyBiasLeft),
+            Alignment::JustifyBiasRight => call!(JustifyBiasRight),
         }
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Alignment {
+    fn serialize<Serializer>(
+        &self,
+        serializer: Serializer,
+    ) -> Result<Serializer::Ok, Serializer::Error>
+    where
+        Serializer: serde::Serializer,
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Alignment {
+    fn deserialize<Deserializer>(deserializer: Deserializer) -> Result<Self, Deserializer::Error>
+    where
+        Deserializer: serde::Deserializer<'de>,
+    {
+        struct AlignmentVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for AlignmentVisitor {
+            type Value = Alignment;
+
+            fn expecting(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+                write!(formatter, "one of")?;
+                for (index, alignment) in Alignment::ALL.iter().enumerate() {
+                    let separator = if index == 0 { " " } else { ", " };
+                    write!(formatter, "{}{:?}", separator, alignment.name())?;
+                }
+                Ok(())
+            }
+
+            fn visit_str<Error>(self, text: &str) -> Result<Self::Value, Error>
+            where
+                Error: serde::de::Error,
+            {
+                text.parse()
+                    .map_err(|_| Error::invalid_value(serde::de::Unexpected::Str(text), &self))
+            }
+        }
+
+        deserializer.deserialize_str(AlignmentVisitor)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::CharWidthCallback;
+use core::fmt::Error;
+use unicode_width::UnicodeWidthChar;
+
+/// State of [`AnsiParser`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Outside of any escape sequence.
+    Text,
+    /// Right after an `ESC`.
+    Escape,
+    /// Inside an escape sequence that awaits a final byte after some intermediate bytes.
+    Intermediate,
+    /// Inside a Control Sequence Introducer (CSI) sequence.
+    Control,
+    /// Inside an Operating System Command (OSC) or another command string.
+    Command,
+    /// Right after an `ESC` inside a command string.
+    CommandEscape,
+}
+
+/// Classify characters of a text as visible or as part of ANSI escape sequences.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AnsiParser {
+    state: State,
+}
+
+impl AnsiParser {
+    /// Start parsing outside of any escape sequence.
+    pub(crate) fn new() -> Self {
+        AnsiParser { state: State::Text }
+    }
+
+    /// Feed the next character, return `true` if it is a visible character.
+    pub(crate) fn is_visible(&mut self, character: char) -> bool {
+        use State::*;
+        match self.state {
+            Text => match character {
+                '\u{1B}' => self.state = Escape,
+                '\u{9B}' => self.state = Control,
+                '\u{90}' | '\u{98}' | '\u{9D}' | '\u{9E}' | '\u{9F}' => self.state = Command,
+                _ => return true,
+            },
+            Escape => self.escape(character),
+            Intermediate => {
+                if !('\u{20}'..='\u{2F}').contains(&character) {
+                    self.state = Text;
+                }
+            }
+            Control => {
+                if ('\u{40}'..='\u{7E}').contains(&character) {
+                    self.state = Text;
+                }
+            }
+            Command => match character {
+                '\u{07}' | '\u{9C}' => self.state = Text,
+                '\u{1B}' => self.state = CommandEscape,
+                _ => {}
+            },
+            CommandEscape => match character {
+                '\\' => self.state = Text,
+                _ => self.escape(character),
+            },
+        }
+        false
+    }
+
+    /// Handle the character that follows an `ESC`.
+    fn escape(&mut self, character: char) {
+        use State::*;
+        self.state = match character {
+            '[' => Control,
+            ']' | 'P' | 'X' | '^' | '_' => Command,
+            '\u{20}'..='\u{2F}' => Intermediate,
+            _ => Text,
+        };
+    }
+}
+
+/// Sum of [`UnicodeWidthChar::width`] of every visible character of a text.
+pub(crate) fn ansi_unicode_width(text: &str) -> usize {
+    let mut parser = AnsiParser::new();
+    text.chars()
+        .filter(|character| parser.is_visible(*character))
+        .map(|character| UnicodeWidthChar::width(character).unwrap_or(0))
+        .sum()
+}
+
+/// Report the characters of a text to [`Width::for_each_char_width`](crate::Width::for_each_char_width),
+/// characters of ANSI escape sequences are not measured.
+pub(crate) fn ansi_char_widths(text: &str, callback: CharWidthCallback) -> Result<(), Error> {
+    let mut parser = AnsiParser::new();
+    text.chars().try_for_each(|character| {
+        if parser.is_visible(character) {
+            callback(
+                character,
+                Some(UnicodeWidthChar::width(character).unwrap_or(0)),
+            )
+        } else {
+            callback(character, None)
+        }
+    })
+}
@@ -0,0 +1,339 @@
+use crate::{
+    spec::{char_at, parse_spec, SpecError},
+    CharWidthCallback, FormatSpec, Width,
+};
+use core::fmt::{Display, Error, Formatter};
+
+/// [`Width`] wrapper of a trait object.
+struct DynWidth<'a>(&'a dyn Width);
+
+impl<'a> Display for DynWidth<'a> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(formatter, "{}", self.0)
+    }
+}
+
+impl<'a> Width for DynWidth<'a> {
+    fn width(&self) -> usize {
+        self.0.width()
+    }
+
+    fn for_each_char_width(&self, callback: CharWidthCallback<'_>) -> Result<(), Error> {
+        self.0.for_each_char_width(callback)
+    }
+}
+
+/// Piece of the template of [`PadArguments`], created by [`pad_args!`](crate::pad_args).
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateSegment {
+    /// Text of the template between two byte offsets.
+    Text(usize, usize),
+    /// Argument at an index, displayed as is.
+    Plain(usize),
+    /// Argument at an index, padded by a spec whose pad block is the character
+    /// at a byte offset of the template (if any).
+    Padded(usize, FormatSpec, Option<usize>),
+}
+
+/// Template whose placeholders are padded by their [`Width`], created by [`pad_args!`](crate::pad_args).
+///
+/// The template is parsed when the macro is compiled, displaying it only walks the parsed pieces.
+///
+/// **Key traits:**
+/// * [`Display`]: Displays the template with the padded arguments.
+#[derive(Clone, Copy)]
+pub struct PadArguments<'a> {
+    #[doc(hidden)]
+    pub template: &'a str,
+    #[doc(hidden)]
+    pub segments: &'a [TemplateSegment],
+    #[doc(hidden)]
+    pub arguments: &'a [&'a dyn Width],
+}
+
+impl<'a> Display for PadArguments<'a> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        for segment in self.segments {
+            match *segment {
+                TemplateSegment::Text(start, end) => {
+                    formatter.write_str(&self.template[start..end])?;
+                }
+                TemplateSegment::Plain(argument) => {
+                    write!(formatter, "{}", self.arguments[argument])?;
+                }
+                TemplateSegment::Padded(argument, mut spec, fill) => {
+                    if let Some(fill) = fill {
+                        spec.pad_block = char_at(self.template, fill);
+                    }
+                    let value = DynWidth(self.arguments[argument]);
+                    write!(formatter, "{}", spec.pad(value))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Mistake in the template of [`pad_args!`](crate::pad_args), see [`fail`].
+#[derive(Debug, Clone, Copy)]
+enum TemplateError {
+    UnmatchedBrace(usize),
+    UnclosedPlaceholder(usize),
+    UnknownArgument(usize),
+    UnusedArgument(usize),
+    Spec(SpecError),
+}
+
+/// Stop the compilation of [`pad_args!`](crate::pad_args) on a mistake in its template.
+///
+/// Panicking in a constant requires a newer compiler than this crate supports,
+/// so the mistake is reported by indexing an empty array whose name describes it.
+/// The index is the byte offset of the mistake in the template,
+/// or the index of the argument that is not used.
+const fn fail(error: TemplateError) -> usize {
+    match error {
+        TemplateError::UnmatchedBrace(offset) => {
+            let unmatched_closing_brace_in_template: [usize; 0] = [];
+            unmatched_closing_brace_in_template[offset]
+        }
+        TemplateError::UnclosedPlaceholder(offset) => {
+            let unclosed_placeholder_in_template: [usize; 0] = [];
+            unclosed_placeholder_in_template[offset]
+        }
+        TemplateError::UnknownArgument(offset) => {
+            let unknown_argument_in_template: [usize; 0] = [];
+            unknown_argument_in_template[offset]
+        }
+        TemplateError::UnusedArgument(index) => {
+            let argument_never_used_in_template: [usize; 0] = [];
+            argument_never_used_in_template[index]
+        }
+        TemplateError::Spec(SpecError::MissingWidth(offset)) => {
+            let missing_width_in_format_spec: [usize; 0] = [];
+            missing_width_in_format_spec[offset]
+        }
+        TemplateError::Spec(SpecError::WidthOverflow(offset)) => {
+            let width_is_too_large_in_format_spec: [usize; 0] = [];
+            width_is_too_large_in_format_spec[offset]
+        }
+        TemplateError::Spec(SpecError::UnexpectedCharacter(offset)) => {
+            let unexpected_character_in_format_spec: [usize; 0] = [];
+            unexpected_character_in_format_spec[offset]
+        }
+    }
+}
+
+/// Position of the parser of a template.
+#[derive(Debug, Clone, Copy)]
+struct Cursor {
+    /// Byte offset of the rest of the template.
+    offset: usize,
+    /// Index of the argument of the next `{}`.
+    next_position: usize,
+}
+
+/// Find the index of the argument that is named by `bytes[start..end]`.
+const fn find_argument(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    names: &[Option<&str>],
+) -> Option<usize> {
+    let mut index: usize = 0;
+    let mut is_index = true;
+    let mut offset = start;
+    while offset < end {
+        if !bytes[offset].is_ascii_digit() {
+            is_index = false;
+        }
+        offset += 1;
+    }
+    if is_index {
+        offset = start;
+        while offset < end {
+            let digit = (bytes[offset] - b'0') as usize;
+            index = match index.checked_mul(10) {
+                Some(index) => match index.checked_add(digit) {
+                    Some(index) => index,
+                    None => return None,
+                },
+                None => return None,
+            };
+            offset += 1;
+        }
+        return Some(index);
+    }
+    while index < names.len() {
+        if let Some(name) = names[index] {
+            let name = name.as_bytes();
+            if name.len() == end - start {
+                let mut offset = 0;
+                while offset < name.len() && name[offset] == bytes[start + offset] {
+                    offset += 1;
+                }
+                if offset == name.len() {
+                    return Some(index);
+                }
+            }
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Parse the segment of a template that starts at a cursor.
+///
+/// Return the segment along with the cursor after it, or [`None`] at the end of the template.
+const fn next_segment(
+    template: &str,
+    names: &[Option<&str>],
+    cursor: Cursor,
+) -> Result<Option<(TemplateSegment, Cursor)>, TemplateError> {
+    let bytes = template.as_bytes();
+    let Cursor {
+        offset,
+        mut next_position,
+    } = cursor;
+    if offset >= bytes.len() {
+        return Ok(None);
+    }
+
+    let doubled = offset + 1 < bytes.len() && bytes[offset + 1] == bytes[offset];
+    match bytes[offset] {
+        b'{' | b'}' if doubled => {
+            let segment = TemplateSegment::Text(offset, offset + 1);
+            let cursor = Cursor {
+                offset: offset + 2,
+                next_position,
+            };
+            return Ok(Some((segment, cursor)));
+        }
+        b'}' => return Err(TemplateError::UnmatchedBrace(offset)),
+        b'{' => {}
+        _ => {
+            let mut end = offset;
+            while end < bytes.len() && bytes[end] != b'{' && bytes[end] != b'}' {
+                end += 1;
+            }
+            let segment = TemplateSegment::Text(offset, end);
+            let cursor = Cursor {
+                offset: end,
+                next_position,
+            };
+            return Ok(Some((segment, cursor)));
+        }
+    }
+
+    let mut end = offset + 1;
+    while end < bytes.len() && bytes[end] != b'}' {
+        end += 1;
+    }
+    if end == bytes.len() {
+        return Err(TemplateError::UnclosedPlaceholder(offset));
+    }
+    let mut colon = offset + 1;
+    while colon < end && bytes[colon] != b':' {
+        colon += 1;
+    }
+
+    let argument = if colon == offset + 1 {
+        next_position += 1;
+        next_position - 1
+    } else {
+        match find_argument(bytes, offset + 1, colon, names) {
+            Some(argument) => argument,
+            None => return Err(TemplateError::UnknownArgument(offset)),
+        }
+    };
+    if argument >= names.len() {
+        return Err(TemplateError::UnknownArgument(offset));
+    }
+
+    let segment = if colon == end {
+        TemplateSegment::Plain(argument)
+    } else {
+        match parse_spec(bytes, colon + 1, end) {
+            Ok((spec, fill)) => TemplateSegment::Padded(argument, spec, fill),
+            Err(error) => return Err(TemplateError::Spec(error)),
+        }
+    };
+    let cursor = Cursor {
+        offset: end + 1,
+        next_position,
+    };
+    Ok(Some((segment, cursor)))
+}
+
+/// Whether a placeholder of a template refers to the argument at an index.
+const fn uses_argument(template: &str, names: &[Option<&str>], argument: usize) -> bool {
+    let mut cursor = Cursor {
+        offset: 0,
+        next_position: 0,
+    };
+    while let Ok(Some((segment, next))) = next_segment(template, names, cursor) {
+        match segment {
+            TemplateSegment::Plain(index) | TemplateSegment::Padded(index, _, _)
+                if index == argument =>
+            {
+                return true;
+            }
+            _ => {}
+        }
+        cursor = next;
+    }
+    false
+}
+
+/// Count the segments of the template of [`pad_args!`](crate::pad_args).
+///
+/// The compilation stops when the template has a mistake, see [`fail`].
+#[doc(hidden)]
+pub const fn count_template_segments(template: &str, names: &[Option<&str>]) -> usize {
+    let mut count = 0;
+    let mut cursor = Cursor {
+        offset: 0,
+        next_position: 0,
+    };
+    loop {
+        match next_segment(template, names, cursor) {
+            Ok(Some((_, next))) => {
+                count += 1;
+                cursor = next;
+            }
+            Ok(None) => break,
+            Err(error) => return fail(error),
+        }
+    }
+    let mut argument = 0;
+    while argument < names.len() {
+        if !uses_argument(template, names, argument) {
+            return fail(TemplateError::UnusedArgument(argument));
+        }
+        argument += 1;
+    }
+    count
+}
+
+/// Parse the template of [`pad_args!`](crate::pad_args) that has `N` segments
+/// (see [`count_template_segments`]).
+#[doc(hidden)]
+pub const fn parse_template<const N: usize>(
+    template: &str,
+    names: &[Option<&str>],
+) -> [TemplateSegment; N] {
+    let mut segments = [TemplateSegment::Text(0, 0); N];
+    let mut cursor = Cursor {
+        offset: 0,
+        next_position: 0,
+    };
+    let mut index = 0;
+    while index < N {
+        if let Ok(Some((segment, next))) = next_segment(template, names, cursor) {
+            segments[index] = segment;
+            cursor = next;
+        }
+        index += 1;
+    }
+    segments
+}
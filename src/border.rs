@@ -0,0 +1,187 @@
+use crate::ParseTableStyleError;
+use core::{
+    fmt::{Display, Error, Formatter},
+    str::FromStr,
+};
+
+/// Horizontal line of a table border, such as `+-----+---+`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderRule {
+    /// Leftmost part of the line.
+    pub left: &'static str,
+    /// Character that is repeated over every column.
+    pub fill: char,
+    /// Part of the line between every two adjacent columns.
+    pub junction: &'static str,
+    /// Rightmost part of the line.
+    pub right: &'static str,
+}
+
+/// Characters that draw the border of a table, see [`BorderedTable`](crate::BorderedTable).
+///
+/// A line without a rule is not drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Border {
+    /// Line above the first row.
+    pub top: Option<BorderRule>,
+    /// Line between the header row and the rest of the rows.
+    pub header: Option<BorderRule>,
+    /// Line below the last row.
+    pub bottom: Option<BorderRule>,
+    /// Part of a row before the first cell.
+    pub left: &'static str,
+    /// Part of a row between every two adjacent cells.
+    pub separator: &'static str,
+    /// Part of a row after the last cell.
+    pub right: &'static str,
+    /// Number of spaces on each side of every cell.
+    pub padding: usize,
+}
+
+/// Create a [`Border`] whose three rules only differ in their corners and junctions.
+macro_rules! boxed {
+    (
+        $fill:literal $vertical:literal
+        [$top_left:literal $top_junction:literal $top_right:literal]
+        [$middle_left:literal $middle_junction:literal $middle_right:literal]
+        [$bottom_left:literal $bottom_junction:literal $bottom_right:literal]
+    ) => {
+        Border {
+            top: Some(BorderRule {
+                left: $top_left,
+                fill: $fill,
+                junction: $top_junction,
+                right: $top_right,
+            }),
+            header: Some(BorderRule {
+                left: $middle_left,
+                fill: $fill,
+                junction: $middle_junction,
+                right: $middle_right,
+            }),
+            bottom: Some(BorderRule {
+                left: $bottom_left,
+                fill: $fill,
+                junction: $bottom_junction,
+                right: $bottom_right,
+            }),
+            left: $vertical,
+            separator: $vertical,
+            right: $vertical,
+            padding: 1,
+        }
+    };
+}
+
+/// Predefined [`Border`] that can be chosen by name.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::TableStyle;
+/// let style: TableStyle = "rounded".parse().unwrap();
+/// assert_eq!(style, TableStyle::Rounded);
+/// assert_eq!(style.to_string(), "rounded");
+/// assert_eq!(style.border().top.unwrap().left, "╭");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableStyle {
+    /// `+---+---+` and `|`.
+    Ascii,
+    /// `┌───┬───┐` and `│`.
+    Light,
+    /// `┏━━━┳━━━┓` and `┃`.
+    Heavy,
+    /// `╔═══╦═══╗` and `║`.
+    Double,
+    /// `╭───┬───╮` and `│`.
+    Rounded,
+    /// Markdown pipe table: `|---|---|` under the header only.
+    Markdown,
+    /// Cells separated by a space, without lines.
+    Borderless,
+}
+
+impl TableStyle {
+    /// All variants in declaration order.
+    pub const ALL: [TableStyle; 7] = [
+        TableStyle::Ascii,
+        TableStyle::Light,
+        TableStyle::Heavy,
+        TableStyle::Double,
+        TableStyle::Rounded,
+        TableStyle::Markdown,
+        TableStyle::Borderless,
+    ];
+
+    /// Stable name of the variant.
+    pub const fn name(self) -> &'static str {
+        match self {
+            TableStyle::Ascii => "ascii",
+            TableStyle::Light => "light",
+            TableStyle::Heavy => "heavy",
+            TableStyle::Double => "double",
+            TableStyle::Rounded => "rounded",
+            TableStyle::Markdown => "markdown",
+            TableStyle::Borderless => "borderless",
+        }
+    }
+
+    /// Characters that draw the border.
+    pub const fn border(self) -> Border {
+        match self {
+            TableStyle::Ascii => boxed!('-' "|" ["+" "+" "+"] ["+" "+" "+"] ["+" "+" "+"]),
+            TableStyle::Light => boxed!('─' "│" ["┌" "┬" "┐"] ["├" "┼" "┤"] ["└" "┴" "┘"]),
+            TableStyle::Heavy => boxed!('━' "┃" ["┏" "┳" "┓"] ["┣" "╋" "┫"] ["┗" "┻" "┛"]),
+            TableStyle::Double => boxed!('═' "║" ["╔" "╦" "╗"] ["╠" "╬" "╣"] ["╚" "╩" "╝"]),
+            TableStyle::Rounded => boxed!('─' "│" ["╭" "┬" "╮"] ["├" "┼" "┤"] ["╰" "┴" "╯"]),
+            TableStyle::Markdown => Border {
+                top: None,
+                header: Some(BorderRule {
+                    left: "|",
+                    fill: '-',
+                    junction: "|",
+                    right: "|",
+                }),
+                bottom: None,
+                left: "|",
+                separator: "|",
+                right: "|",
+                padding: 1,
+            },
+            TableStyle::Borderless => Border {
+                top: None,
+                header: None,
+                bottom: None,
+                left: "",
+                separator: " ",
+                right: "",
+                padding: 0,
+            },
+        }
+    }
+}
+
+impl Display for TableStyle {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(formatter, "{}", self.name())
+    }
+}
+
+impl FromStr for TableStyle {
+    type Err = ParseTableStyleError;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        TableStyle::ALL
+            .iter()
+            .copied()
+            .find(|style| style.name() == text)
+            .ok_or(ParseTableStyleError)
+    }
+}
+
+impl From<TableStyle> for Border {
+    fn from(style: TableStyle) -> Self {
+        style.border()
+    }
+}
@@ -0,0 +1,357 @@
+#![cfg(feature = "std")]
+
+use crate::{Alignment, Border, BorderRule, PaddedRow, PaddedTableIter, Width};
+use derive_builder::Builder;
+use std::{
+    fmt::{Display, Error, Formatter},
+    rc::Rc,
+};
+
+/// Pad all values in a table like [`PaddedTable`](crate::PaddedTable) then draw a border around the cells.
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`IntoIterator`]: Build an iterator of lines, see [`BorderedLine`].
+///
+/// When `header` is `true`, the [`header`](Border::header) rule is drawn after the first row.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{Alignment, BorderedTable, TableStyle};
+/// let rows = vec![
+///     vec!["Language", "Year"],
+///     vec!["Rust", "2010"],
+///     vec!["JavaScript", "1995"],
+/// ];
+/// let render = |style: TableStyle| {
+///     let bordered_table = BorderedTable {
+///         rows: rows.iter(),
+///         pad_block: ' ',
+///         pads: vec![Alignment::Left, Alignment::Right],
+///         border: style.border(),
+///         header: true,
+///     };
+///     bordered_table
+///         .into_iter()
+///         .map(|x| x.to_string())
+///         .collect::<Vec<_>>()
+/// };
+/// assert_eq!(render(TableStyle::Light), [
+///     "┌────────────┬──────┐",
+///     "│ Language   │ Year │",
+///     "├────────────┼──────┤",
+///     "│ Rust       │ 2010 │",
+///     "│ JavaScript │ 1995 │",
+///     "└────────────┴──────┘",
+/// ]);
+/// assert_eq!(render(TableStyle::Markdown), [
+///     "| Language   | Year |",
+///     "|------------|------|",
+///     "| Rust       | 2010 |",
+///     "| JavaScript | 1995 |",
+/// ]);
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct BorderedTable<RowIter, PadBlock = char, Pad = Alignment>
+where
+    RowIter: Iterator,
+    RowIter::Item: IntoIterator,
+    <RowIter::Item as IntoIterator>::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<<RowIter::Item as IntoIterator>::Item, PadBlock> + Default + Copy,
+{
+    /// Rows of values to be padded.
+    pub rows: RowIter,
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
+    pub pad_block: PadBlock,
+    /// Where to place the pad of each column.
+    pub pads: Vec<Pad>,
+    /// Characters that draw the border, such as [`TableStyle::border`](crate::TableStyle::border).
+    pub border: Border,
+    /// Whether the first row is a header.
+    pub header: bool,
+}
+
+impl<RowIter, PadBlock, Pad> IntoIterator for BorderedTable<RowIter, PadBlock, Pad>
+where
+    RowIter: Iterator,
+    RowIter::Item: IntoIterator,
+    <RowIter::Item as IntoIterator>::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<<RowIter::Item as IntoIterator>::Item, PadBlock> + Default + Copy,
+{
+    type Item = BorderedLine<<RowIter::Item as IntoIterator>::Item, PadBlock, Pad>;
+    type IntoIter = BorderedTableIter<<RowIter::Item as IntoIterator>::Item, PadBlock, Pad>;
+    fn into_iter(self) -> Self::IntoIter {
+        let BorderedTable {
+            rows,
+            pad_block,
+            pads,
+            border,
+            header,
+        } = self;
+        let mut table = PaddedTableIter::new(pad_block, pads, border.separator);
+        table.extend(rows);
+        BorderedTableIter {
+            column_widths: table.column_widths().into(),
+            top: border.top,
+            header_rule: border.header.filter(|_| header),
+            bottom: border.bottom,
+            first_row_done: false,
+            table,
+            border,
+        }
+    }
+}
+
+/// Iterator created by calling [`into_iter`](IntoIterator::into_iter) on [`BorderedTable`].
+///
+/// **Required features:** `std`
+#[derive(Debug, Clone)]
+pub struct BorderedTableIter<Value, PadBlock = char, Pad = Alignment>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Default + Copy,
+{
+    table: PaddedTableIter<Value, PadBlock, Pad, &'static str>,
+    /// Column widths of the table, shared by every line.
+    column_widths: Rc<[usize]>,
+    border: Border,
+    top: Option<BorderRule>,
+    header_rule: Option<BorderRule>,
+    bottom: Option<BorderRule>,
+    first_row_done: bool,
+}
+
+impl<Value, PadBlock, Pad> BorderedTableIter<Value, PadBlock, Pad>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Default + Copy,
+{
+    /// Pad block that was used in the construction of [`BorderedTable`].
+    pub fn pad_block(&self) -> PadBlock {
+        self.table.pad_block()
+    }
+
+    /// Padding methods that were used in the construction of [`BorderedTable`].
+    pub fn pads(&self) -> &'_ [Pad] {
+        self.table.pads()
+    }
+
+    /// Border that was used in the construction of [`BorderedTable`].
+    pub fn border(&self) -> Border {
+        self.border
+    }
+
+    /// Maximum width of all values of each column, excluding the padding of the border.
+    pub fn column_widths(&self) -> &'_ [usize] {
+        &self.column_widths
+    }
+
+    /// Width of every line.
+    pub fn total_width(&self) -> usize {
+        bordered_width(
+            self.column_widths(),
+            self.border.padding,
+            self.border.left,
+            self.border.separator,
+            self.border.right,
+        )
+    }
+
+    /// Wrap the content of a line with the column widths and the border.
+    fn line(
+        &self,
+        content: LineContent<Value, PadBlock, Pad>,
+    ) -> BorderedLine<Value, PadBlock, Pad> {
+        BorderedLine {
+            content,
+            column_widths: Rc::clone(&self.column_widths),
+            pad_block: self.pad_block(),
+            border: self.border,
+        }
+    }
+}
+
+impl<Value, PadBlock, Pad> Iterator for BorderedTableIter<Value, PadBlock, Pad>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Default + Copy,
+{
+    type Item = BorderedLine<Value, PadBlock, Pad>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(rule) = self.top.take() {
+            return Some(self.line(LineContent::Rule(rule)));
+        }
+        if self.first_row_done {
+            if let Some(rule) = self.header_rule.take() {
+                return Some(self.line(LineContent::Rule(rule)));
+            }
+        }
+        if let Some(row) = self.table.next() {
+            self.first_row_done = true;
+            return Some(self.line(LineContent::Row(row)));
+        }
+        self.header_rule = None;
+        let rule = self.bottom.take()?;
+        Some(self.line(LineContent::Rule(rule)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<Value, PadBlock, Pad> ExactSizeIterator for BorderedTableIter<Value, PadBlock, Pad>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Default + Copy,
+{
+    fn len(&self) -> usize {
+        let rows = self.table.len();
+        let header_rule = self.header_rule.is_some() && (self.first_row_done || rows != 0);
+        self.top.is_some() as usize + header_rule as usize + rows + self.bottom.is_some() as usize
+    }
+}
+
+/// Content of a [`BorderedLine`].
+#[derive(Debug, Clone)]
+enum LineContent<Value, PadBlock, Pad>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+{
+    Rule(BorderRule),
+    Row(PaddedRow<Value, PadBlock, Pad, &'static str>),
+}
+
+/// A line of a [`BorderedTable`], yielded by [`BorderedTableIter`].
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`Display`]: Displays either a rule or a row of padded values between vertical lines.
+/// * [`Width`]: Get width of the line.
+#[derive(Debug, Clone)]
+pub struct BorderedLine<Value, PadBlock = char, Pad = Alignment>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+{
+    content: LineContent<Value, PadBlock, Pad>,
+    column_widths: Rc<[usize]>,
+    pad_block: PadBlock,
+    border: Border,
+}
+
+impl<Value, PadBlock, Pad> BorderedLine<Value, PadBlock, Pad>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+{
+    /// The rule of the line, [`None`] if the line is a row.
+    pub fn rule(&self) -> Option<BorderRule> {
+        match &self.content {
+            LineContent::Rule(rule) => Some(*rule),
+            LineContent::Row(_) => None,
+        }
+    }
+
+    /// The padded values of the line, [`None`] if the line is a rule.
+    pub fn row(&self) -> Option<&'_ PaddedRow<Value, PadBlock, Pad, &'static str>> {
+        match &self.content {
+            LineContent::Rule(_) => None,
+            LineContent::Row(row) => Some(row),
+        }
+    }
+}
+
+impl<Value, PadBlock, Pad> Display for BorderedLine<Value, PadBlock, Pad>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        let padding = self.border.padding;
+        match &self.content {
+            LineContent::Rule(rule) => {
+                write!(formatter, "{}", rule.left)?;
+                for (index, width) in self.column_widths.iter().enumerate() {
+                    if index != 0 {
+                        write!(formatter, "{}", rule.junction)?;
+                    }
+                    crate::PadBlock::fill(&rule.fill, formatter, width + 2 * padding)?;
+                }
+                write!(formatter, "{}", rule.right)
+            }
+            LineContent::Row(row) => {
+                write!(formatter, "{}", self.border.left)?;
+                for (index, width) in self.column_widths.iter().enumerate() {
+                    if index != 0 {
+                        write!(formatter, "{}", self.border.separator)?;
+                    }
+                    crate::PadBlock::fill(&' ', formatter, padding)?;
+                    match row.cells().get(index) {
+                        Some(cell) => write!(formatter, "{}", cell)?,
+                        None => self.pad_block.fill(formatter, *width)?,
+                    }
+                    crate::PadBlock::fill(&' ', formatter, padding)?;
+                }
+                write!(formatter, "{}", self.border.right)
+            }
+        }
+    }
+}
+
+impl<Value, PadBlock, Pad> Width for BorderedLine<Value, PadBlock, Pad>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+{
+    fn width(&self) -> usize {
+        let padding = self.border.padding;
+        match &self.content {
+            LineContent::Rule(rule) => bordered_width(
+                &self.column_widths,
+                padding,
+                rule.left,
+                rule.junction,
+                rule.right,
+            ),
+            LineContent::Row(_) => bordered_width(
+                &self.column_widths,
+                padding,
+                self.border.left,
+                self.border.separator,
+                self.border.right,
+            ),
+        }
+    }
+}
+
+/// Width of a line whose columns are surrounded by `padding` and separated by `separator`.
+fn bordered_width(
+    column_widths: &[usize],
+    padding: usize,
+    left: &str,
+    separator: &str,
+    right: &str,
+) -> usize {
+    let separator_count = column_widths.len().saturating_sub(1);
+    let columns_width: usize = column_widths.iter().map(|width| width + 2 * padding).sum();
+    left.width() + columns_width + separator_count * separator.width() + right.width()
+}
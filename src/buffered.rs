@@ -0,0 +1,210 @@
+use crate::{Alignment, CapacityError, PaddedValue, PanicOnExcess, Width};
+use core::{cmp::max, marker::PhantomData};
+use fmt_iter::FmtIter;
+
+/// Counterpart of [`PaddedColumnIter`](crate::PaddedColumnIter) that stores values in a fixed-capacity buffer.
+///
+/// The buffer is either supplied by the caller (e.g. `&mut [Option<Value>]`)
+/// or an inline array of `N` slots (see [`new_inline`](BufferedColumnIter::new_inline)).
+/// Its content before the construction is ignored.
+///
+/// It does not implement [`Extend`], which cannot report a full buffer,
+/// use [`try_extend`](BufferedColumnIter::try_extend) instead.
+///
+/// **Key traits:**
+/// * [`Iterator`]: Iterate over the padded values.
+///
+/// **Example:** Use an inline buffer
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{BufferedColumnIter, Alignment};
+/// let mut iter = BufferedColumnIter::<_, [_; 4]>::new_inline(' ', Alignment::Right);
+/// iter.try_extend(["Rust", "C", "JavaScript"].iter()).unwrap();
+/// assert_eq!(iter.total_width(), 10);
+/// let padded_values: Vec<_> = iter.map(|x| x.to_string()).collect();
+/// assert_eq!(padded_values, ["      Rust", "         C", "JavaScript"]);
+/// ```
+///
+/// **Example:** Use a caller-supplied buffer
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{BufferedColumnIter, CapacityError, AlignLeft};
+/// let mut buffer = [None, None];
+/// let mut iter = BufferedColumnIter::new(&mut buffer[..], '-', AlignLeft);
+/// assert_eq!(iter.try_push_back("abc"), Ok(()));
+/// assert_eq!(iter.try_push_back("de"), Ok(()));
+/// assert_eq!(
+///     iter.try_push_back("fghi"),
+///     Err(CapacityError { value: "fghi", capacity: 2 }),
+/// );
+/// let padded_values: Vec<_> = iter.map(|x| x.to_string()).collect();
+/// assert_eq!(padded_values, ["abc", "de-"]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BufferedColumnIter<Value, Buffer, PadBlock = char, Pad = Alignment>
+where
+    Value: Width,
+    Buffer: AsRef<[Option<Value>]> + AsMut<[Option<Value>]>,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+{
+    buffer: Buffer,
+    head: usize,
+    len: usize,
+    pad_block: PadBlock,
+    pad: Pad,
+    total_width: usize,
+    _value: PhantomData<Value>,
+}
+
+impl<Value, Buffer, PadBlock, Pad> BufferedColumnIter<Value, Buffer, PadBlock, Pad>
+where
+    Value: Width,
+    Buffer: AsRef<[Option<Value>]> + AsMut<[Option<Value>]>,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+{
+    /// Initialize an empty iterator with a buffer.
+    pub fn new(mut buffer: Buffer, pad_block: PadBlock, pad: Pad) -> Self {
+        for slot in buffer.as_mut() {
+            *slot = None;
+        }
+        BufferedColumnIter {
+            buffer,
+            head: 0,
+            len: 0,
+            total_width: 0,
+            pad_block,
+            pad,
+            _value: PhantomData,
+        }
+    }
+
+    /// Maximum number of values the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.buffer.as_ref().len()
+    }
+
+    /// Whether the buffer cannot hold any more value.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Add a value to the column.
+    /// If width of the new value is greater than the current total_width,
+    /// set it as the new total_width.
+    ///
+    /// Return the value in an error if the buffer is full.
+    pub fn try_push_back(&mut self, value: Value) -> Result<(), CapacityError<Value>> {
+        let capacity = self.capacity();
+        if self.len == capacity {
+            return Err(CapacityError { value, capacity });
+        }
+        self.total_width = max(self.total_width, value.width());
+        let index = (self.head + self.len) % capacity;
+        self.buffer.as_mut()[index] = Some(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Add values to the column until the buffer is full.
+    ///
+    /// Return the first value that does not fit in an error.
+    pub fn try_extend<Values>(&mut self, values: Values) -> Result<(), CapacityError<Value>>
+    where
+        Values: IntoIterator<Item = Value>,
+    {
+        values
+            .into_iter()
+            .try_for_each(|value| self.try_push_back(value))
+    }
+
+    /// Pad block that was used in the construction of the iterator.
+    pub fn pad_block(&self) -> PadBlock {
+        self.pad_block
+    }
+
+    /// Padding method that was used in the construction of the iterator.
+    pub fn pad(&self) -> Pad {
+        self.pad
+    }
+
+    /// Maximum width of all items that were added to the iterator.
+    pub fn total_width(&self) -> usize {
+        self.total_width
+    }
+}
+
+impl<Value, PadBlock, Pad, const N: usize>
+    BufferedColumnIter<Value, [Option<Value>; N], PadBlock, Pad>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+{
+    const EMPTY_SLOT: Option<Value> = None;
+
+    /// Initialize an empty iterator with an inline buffer of `N` slots.
+    pub fn new_inline(pad_block: PadBlock, pad: Pad) -> Self {
+        BufferedColumnIter::new([Self::EMPTY_SLOT; N], pad_block, pad)
+    }
+}
+
+impl<Value, Buffer, PadBlock, Pad> Iterator for BufferedColumnIter<Value, Buffer, PadBlock, Pad>
+where
+    Value: Width,
+    Buffer: AsRef<[Option<Value>]> + AsMut<[Option<Value>]>,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+{
+    type Item = PaddedValue<Value, PadBlock, PanicOnExcess, Pad>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = self.buffer.as_mut()[self.head].take()?;
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+        Some(PaddedValue {
+            value,
+            pad_block: self.pad_block,
+            pad: self.pad,
+            total_width: self.total_width,
+            handle_excess: PanicOnExcess,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<Value, Buffer, PadBlock, Pad> ExactSizeIterator
+    for BufferedColumnIter<Value, Buffer, PadBlock, Pad>
+where
+    Value: Width,
+    Buffer: AsRef<[Option<Value>]> + AsMut<[Option<Value>]>,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<Value, Buffer, PadBlock, Pad> Width
+    for FmtIter<BufferedColumnIter<Value, Buffer, PadBlock, Pad>>
+where
+    Value: Width + Clone,
+    Buffer: AsRef<[Option<Value>]> + AsMut<[Option<Value>]> + Clone,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+{
+    fn width(&self) -> usize {
+        self.total_width() * self.len()
+    }
+}
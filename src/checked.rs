@@ -0,0 +1,154 @@
+use crate::{Alignment, ColumnExcessError, PaddedValue, PanicOnExcess, Width};
+
+/// Pad all values in a collection to a fixed width, report values that exceed it.
+///
+/// **Key traits:**
+/// * [`IntoIterator`]: Build an iterator of padded values or errors.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{CheckedColumn, AlignRight, ColumnExcessError, ExcessError};
+/// let values = ["Rust", "C", "JavaScript", "Go"];
+/// let checked_column = CheckedColumn {
+///     values: values.iter(),
+///     pad_block: ' ',
+///     total_width: 6,
+///     pad: AlignRight,
+/// };
+/// let actual: Vec<_> = checked_column
+///     .into_iter()
+///     .map(|x| x.map(|x| x.to_string()))
+///     .collect();
+/// let expected = [
+///     Ok("  Rust".to_string()),
+///     Ok("     C".to_string()),
+///     Err(ColumnExcessError {
+///         index: 2,
+///         excess: ExcessError {
+///             value_width: 10,
+///             total_width: 6,
+///         },
+///     }),
+///     Ok("    Go".to_string()),
+/// ];
+/// assert_eq!(actual, expected);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CheckedColumn<ValueIter, PadBlock = char, Pad = Alignment>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    /// Values to be padded.
+    pub values: ValueIter,
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
+    pub pad_block: PadBlock,
+    /// Total width to fulfill.
+    pub total_width: usize,
+    /// Where to place the pad.
+    pub pad: Pad,
+}
+
+impl<ValueIter, PadBlock, Pad> IntoIterator for CheckedColumn<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    type Item =
+        Result<PaddedValue<ValueIter::Item, PadBlock, PanicOnExcess, Pad>, ColumnExcessError>;
+    type IntoIter = CheckedColumnIter<ValueIter, PadBlock, Pad>;
+    fn into_iter(self) -> Self::IntoIter {
+        CheckedColumnIter {
+            column: self,
+            index: 0,
+        }
+    }
+}
+
+/// Iterator created by calling [`into_iter`](IntoIterator::into_iter) on [`CheckedColumn`].
+#[derive(Debug, Clone, Copy)]
+pub struct CheckedColumnIter<ValueIter, PadBlock = char, Pad = Alignment>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    column: CheckedColumn<ValueIter, PadBlock, Pad>,
+    index: usize,
+}
+
+impl<ValueIter, PadBlock, Pad> CheckedColumnIter<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    /// Pad block that was used in the construction of [`CheckedColumn`].
+    pub fn pad_block(&self) -> PadBlock {
+        self.column.pad_block
+    }
+
+    /// Padding method that was used in the construction of [`CheckedColumn`].
+    pub fn pad(&self) -> Pad {
+        self.column.pad
+    }
+
+    /// Total width that was used in the construction of [`CheckedColumn`].
+    pub fn total_width(&self) -> usize {
+        self.column.total_width
+    }
+}
+
+impl<ValueIter, PadBlock, Pad> Iterator for CheckedColumnIter<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    type Item =
+        Result<PaddedValue<ValueIter::Item, PadBlock, PanicOnExcess, Pad>, ColumnExcessError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.column.values.next()?;
+        let index = self.index;
+        self.index += 1;
+        let padded_value = PaddedValue {
+            value,
+            pad_block: self.column.pad_block,
+            total_width: self.column.total_width,
+            pad: self.column.pad,
+            handle_excess: PanicOnExcess,
+        };
+        Some(
+            padded_value
+                .check()
+                .map(|()| padded_value)
+                .map_err(|excess| ColumnExcessError { index, excess }),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.column.values.size_hint()
+    }
+}
+
+impl<ValueIter, PadBlock, Pad> ExactSizeIterator for CheckedColumnIter<ValueIter, PadBlock, Pad>
+where
+    ValueIter: ExactSizeIterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    fn len(&self) -> usize {
+        self.column.values.len()
+    }
+}
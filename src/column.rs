@@ -1,9 +1,12 @@
 #![cfg(feature = "std")]
 
-use crate::{Alignment, PaddedValue, PanicOnExcess, Width};
+use crate::{
+    decimal::decimal_widths, AlignDecimal, Alignment, ExcessHandler, PaddedValue, PanicOnExcess,
+    TruncateEnd, Width, WidthConstraint,
+};
 use derive_builder::Builder;
 use fmt_iter::FmtIter;
-use std::{cmp::max, collections::LinkedList, fmt::Display};
+use std::{cmp::max, collections::LinkedList};
 
 /// Pad all values in a collection to be of same (maximum) width.
 ///
@@ -42,12 +45,12 @@ pub struct PaddedColumn<ValueIter, PadBlock = char, Pad = Alignment>
 where
     ValueIter: Iterator,
     ValueIter::Item: Width,
-    PadBlock: Display + Copy,
+    PadBlock: crate::PadBlock + Copy,
     Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
 {
     /// Values to be padded.
     pub values: ValueIter,
-    /// Block of the pad (expected to have width of 1).
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
     pub pad_block: PadBlock,
     /// Where to place the pad.
     pub pad: Pad,
@@ -57,7 +60,7 @@ impl<ValueIter, PadBlock, Pad> IntoIterator for PaddedColumn<ValueIter, PadBlock
 where
     ValueIter: Iterator,
     ValueIter::Item: Width,
-    PadBlock: Display + Copy,
+    PadBlock: crate::PadBlock + Copy,
     Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
 {
     type Item = PaddedValue<ValueIter::Item, PadBlock, PanicOnExcess, Pad>;
@@ -74,26 +77,195 @@ where
     }
 }
 
+/// Line all values in a collection up on their decimal separator.
+///
+/// The maximum width of the integer parts and the maximum width of the fractional parts
+/// are computed separately, so that every padded value has the same width.
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`IntoIterator`]: Build an iterator of padded values.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::DecimalColumn;
+/// let values = ["3.14", "120.5", "7", "-0.001"];
+/// let decimal_column = DecimalColumn {
+///     values: values.iter(),
+///     pad_block: ' ',
+///     separator: '.',
+/// };
+/// let padded_values: Vec<_> = decimal_column
+///     .into_iter()
+///     .map(|x| x.to_string())
+///     .collect();
+/// let expected = [
+///     "  3.14 ",
+///     "120.5  ",
+///     "  7    ",
+///     " -0.001",
+/// ];
+/// assert_eq!(padded_values, expected);
+/// ```
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DecimalColumn<ValueIter, PadBlock = char>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+{
+    /// Values to be padded.
+    pub values: ValueIter,
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
+    pub pad_block: PadBlock,
+    /// Character that separates the integer part from the fractional part.
+    pub separator: char,
+}
+
+impl<ValueIter, PadBlock> IntoIterator for DecimalColumn<ValueIter, PadBlock>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+{
+    type Item = PaddedValue<ValueIter::Item, PadBlock, PanicOnExcess, AlignDecimal>;
+    type IntoIter = PaddedColumnIter<ValueIter::Item, PadBlock, AlignDecimal>;
+    fn into_iter(self) -> Self::IntoIter {
+        let DecimalColumn {
+            values,
+            pad_block,
+            separator,
+        } = self;
+        let mut pad = AlignDecimal {
+            separator,
+            integer_width: 0,
+        };
+        let mut fraction_width = 0;
+        let mut iter = PaddedColumnIter::new(pad_block, pad);
+        for value in values {
+            let (value_integer_width, value_fraction_width) = decimal_widths(&value, separator);
+            pad.integer_width = max(pad.integer_width, value_integer_width);
+            fraction_width = max(fraction_width, value_fraction_width);
+            iter.push_back(value);
+        }
+        iter.pad = pad;
+        // `push_back` already took the widest `Value::width()`, which must not be undercut.
+        iter.total_width = max(iter.total_width, pad.integer_width + fraction_width);
+        iter
+    }
+}
+
+/// Pad all values in a collection to a width that is constrained by [`WidthConstraint`].
+///
+/// Values that exceed the constrained width are handled by `handle_excess`,
+/// which cuts off their end ([`TruncateEnd`]) unless another handler is chosen.
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`IntoIterator`]: Build an iterator of padded values.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{AlignLeft, ConstrainedColumn, Ellipsize, WidthConstraint};
+/// let values = ["Rust", "C", "JavaScript"];
+/// let constrained_column = ConstrainedColumn {
+///     values: values.iter(),
+///     pad_block: '.',
+///     pad: AlignLeft,
+///     width_constraint: WidthConstraint {
+///         min_width: 2,
+///         max_width: Some(6),
+///         fixed_width: None,
+///     },
+///     handle_excess: Ellipsize::default(),
+/// };
+/// let padded_values: Vec<_> = constrained_column
+///     .into_iter()
+///     .map(|x| x.to_string())
+///     .collect();
+/// assert_eq!(padded_values, ["Rust..", "C.....", "JavaS…"]);
+/// ```
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct ConstrainedColumn<
+    ValueIter,
+    PadBlock = char,
+    Pad = Alignment,
+    HandleExcess = TruncateEnd,
+> where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+    HandleExcess: ExcessHandler<ValueIter::Item, PadBlock> + Copy,
+{
+    /// Values to be padded.
+    pub values: ValueIter,
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
+    pub pad_block: PadBlock,
+    /// Where to place the pad.
+    pub pad: Pad,
+    /// Limits on the total width.
+    pub width_constraint: WidthConstraint,
+    /// How to write values whose width exceeds the total width.
+    pub handle_excess: HandleExcess,
+}
+
+impl<ValueIter, PadBlock, Pad, HandleExcess> IntoIterator
+    for ConstrainedColumn<ValueIter, PadBlock, Pad, HandleExcess>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+    HandleExcess: ExcessHandler<ValueIter::Item, PadBlock> + Copy,
+{
+    type Item = PaddedValue<ValueIter::Item, PadBlock, HandleExcess, Pad>;
+    type IntoIter = PaddedColumnIter<ValueIter::Item, PadBlock, Pad, HandleExcess>;
+    fn into_iter(self) -> Self::IntoIter {
+        let ConstrainedColumn {
+            values,
+            pad_block,
+            pad,
+            width_constraint,
+            handle_excess,
+        } = self;
+        let mut iter = PaddedColumnIter::new(pad_block, pad)
+            .with_width_constraint(width_constraint)
+            .with_excess_handler(handle_excess);
+        iter.extend(values);
+        iter
+    }
+}
+
 /// Iterator created by calling [`into_iter`](IntoIterator::into_iter) on [`PaddedColumn`].
 ///
 /// **Required features:** `std`
 #[derive(Debug, Clone)]
-pub struct PaddedColumnIter<Value, PadBlock = char, Pad = Alignment>
+pub struct PaddedColumnIter<Value, PadBlock = char, Pad = Alignment, HandleExcess = PanicOnExcess>
 where
     Value: Width,
-    PadBlock: Display + Copy,
+    PadBlock: crate::PadBlock + Copy,
     Pad: crate::Pad<Value, PadBlock> + Copy,
+    HandleExcess: ExcessHandler<Value, PadBlock> + Copy,
 {
     value_list: LinkedList<Value>,
     pad_block: PadBlock,
     pad: Pad,
     total_width: usize,
+    width_constraint: WidthConstraint,
+    handle_excess: HandleExcess,
 }
 
 impl<Value, PadBlock, Pad> PaddedColumnIter<Value, PadBlock, Pad>
 where
     Value: Width,
-    PadBlock: Display + Copy,
+    PadBlock: crate::PadBlock + Copy,
     Pad: crate::Pad<Value, PadBlock> + Copy,
 {
     /// Initialize an empty iterator.
@@ -101,11 +273,35 @@ where
         PaddedColumnIter {
             value_list: LinkedList::new(),
             total_width: 0,
+            width_constraint: WidthConstraint::default(),
+            handle_excess: PanicOnExcess,
             pad_block,
             pad,
         }
     }
 
+    /// Limit the total width.
+    ///
+    /// Values that exceed the limit have their end cut off ([`TruncateEnd`]),
+    /// call [`with_excess_handler`](PaddedColumnIter::with_excess_handler) afterward to handle them differently.
+    pub fn with_width_constraint(
+        self,
+        width_constraint: WidthConstraint,
+    ) -> PaddedColumnIter<Value, PadBlock, Pad, TruncateEnd> {
+        PaddedColumnIter {
+            width_constraint,
+            ..self.with_excess_handler(TruncateEnd)
+        }
+    }
+}
+
+impl<Value, PadBlock, Pad, HandleExcess> PaddedColumnIter<Value, PadBlock, Pad, HandleExcess>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+    HandleExcess: ExcessHandler<Value, PadBlock> + Copy,
+{
     /// Add a value to the column.
     /// If width of the new value is greater than the current total_width,
     /// set it as the new total_width.
@@ -114,6 +310,32 @@ where
         self.value_list.push_back(value);
     }
 
+    /// Replace the handler of values whose width exceeds the total width.
+    pub fn with_excess_handler<NewHandleExcess>(
+        self,
+        handle_excess: NewHandleExcess,
+    ) -> PaddedColumnIter<Value, PadBlock, Pad, NewHandleExcess>
+    where
+        NewHandleExcess: ExcessHandler<Value, PadBlock> + Copy,
+    {
+        let PaddedColumnIter {
+            value_list,
+            pad_block,
+            pad,
+            total_width,
+            width_constraint,
+            handle_excess: _,
+        } = self;
+        PaddedColumnIter {
+            value_list,
+            pad_block,
+            pad,
+            total_width,
+            width_constraint,
+            handle_excess,
+        }
+    }
+
     /// Pad block that was used in the construction of [`PaddedColumn`].
     pub fn pad_block(&self) -> PadBlock {
         self.pad_block
@@ -124,33 +346,48 @@ where
         self.pad
     }
 
-    /// Maximum width of all items that were passed to [`PaddedColumn`].
+    /// Limits on the total width.
+    pub fn width_constraint(&self) -> WidthConstraint {
+        self.width_constraint
+    }
+
+    /// Handler of values whose width exceeds the total width.
+    pub fn handle_excess(&self) -> HandleExcess {
+        self.handle_excess
+    }
+
+    /// Maximum width of all items that were passed to [`PaddedColumn`],
+    /// constrained by [`width_constraint`](PaddedColumnIter::width_constraint).
     pub fn total_width(&self) -> usize {
-        self.total_width
+        self.width_constraint.apply(self.total_width)
     }
 }
 
-impl<Value, PadBlock, Pad> Iterator for PaddedColumnIter<Value, PadBlock, Pad>
+impl<Value, PadBlock, Pad, HandleExcess> Iterator
+    for PaddedColumnIter<Value, PadBlock, Pad, HandleExcess>
 where
     Value: Width,
-    PadBlock: Display + Copy,
+    PadBlock: crate::PadBlock + Copy,
     Pad: crate::Pad<Value, PadBlock> + Copy,
+    HandleExcess: ExcessHandler<Value, PadBlock> + Copy,
 {
-    type Item = PaddedValue<Value, PadBlock, PanicOnExcess, Pad>;
+    type Item = PaddedValue<Value, PadBlock, HandleExcess, Pad>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        let total_width = self.total_width();
         let PaddedColumnIter {
             value_list,
             pad_block,
             pad,
-            total_width,
+            handle_excess,
+            ..
         } = self;
         value_list.pop_front().map(|value| PaddedValue {
             value,
             pad_block: *pad_block,
             pad: *pad,
-            total_width: *total_width,
-            handle_excess: PanicOnExcess,
+            total_width,
+            handle_excess: *handle_excess,
         })
     }
 
@@ -160,22 +397,26 @@ where
     }
 }
 
-impl<Value, PadBlock, Pad> ExactSizeIterator for PaddedColumnIter<Value, PadBlock, Pad>
+impl<Value, PadBlock, Pad, HandleExcess> ExactSizeIterator
+    for PaddedColumnIter<Value, PadBlock, Pad, HandleExcess>
 where
     Value: Width,
-    PadBlock: Display + Copy,
+    PadBlock: crate::PadBlock + Copy,
     Pad: crate::Pad<Value, PadBlock> + Copy,
+    HandleExcess: ExcessHandler<Value, PadBlock> + Copy,
 {
     fn len(&self) -> usize {
         self.value_list.len()
     }
 }
 
-impl<Value, PadBlock, Pad> Extend<Value> for PaddedColumnIter<Value, PadBlock, Pad>
+impl<Value, PadBlock, Pad, HandleExcess> Extend<Value>
+    for PaddedColumnIter<Value, PadBlock, Pad, HandleExcess>
 where
     Value: Width,
-    PadBlock: Display + Copy,
+    PadBlock: crate::PadBlock + Copy,
     Pad: crate::Pad<Value, PadBlock> + Copy,
+    HandleExcess: ExcessHandler<Value, PadBlock> + Copy,
 {
     fn extend<Values: IntoIterator<Item = Value>>(&mut self, values: Values) {
         for value in values {
@@ -184,11 +425,13 @@ where
     }
 }
 
-impl<Value, PadBlock, Pad> Width for FmtIter<PaddedColumnIter<Value, PadBlock, Pad>>
+impl<Value, PadBlock, Pad, HandleExcess> Width
+    for FmtIter<PaddedColumnIter<Value, PadBlock, Pad, HandleExcess>>
 where
     Value: Width + Clone,
-    PadBlock: Display + Copy,
+    PadBlock: crate::PadBlock + Copy,
     Pad: crate::Pad<Value, PadBlock> + Copy,
+    HandleExcess: ExcessHandler<Value, PadBlock> + Copy,
 {
     fn width(&self) -> usize {
         self.total_width() * self.len()
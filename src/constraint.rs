@@ -0,0 +1,52 @@
+/// Limits on the total width of a column.
+///
+/// The total width of a column is the maximum width of its values,
+/// raised to `min_width` and then lowered to `max_width`.
+/// When `fixed_width` is set, it is the total width regardless of the values.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::WidthConstraint;
+/// let constraint = WidthConstraint {
+///     min_width: 4,
+///     max_width: Some(40),
+///     fixed_width: None,
+/// };
+/// assert_eq!(constraint.apply(2), 4);
+/// assert_eq!(constraint.apply(12), 12);
+/// assert_eq!(constraint.apply(100), 40);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WidthConstraint {
+    /// Lower bound of the total width.
+    pub min_width: usize,
+    /// Upper bound of the total width, it takes precedence over `min_width`.
+    pub max_width: Option<usize>,
+    /// Total width that overrides both bounds.
+    pub fixed_width: Option<usize>,
+}
+
+impl WidthConstraint {
+    /// Constraint that makes the total width exactly `width`.
+    pub const fn fixed(width: usize) -> Self {
+        WidthConstraint {
+            min_width: 0,
+            max_width: None,
+            fixed_width: Some(width),
+        }
+    }
+
+    /// Compute the total width of a column whose widest value is `max_value_width` wide.
+    pub fn apply(&self, max_value_width: usize) -> usize {
+        if let Some(fixed_width) = self.fixed_width {
+            return fixed_width;
+        }
+        let width = max_value_width.max(self.min_width);
+        match self.max_width {
+            Some(max_width) => width.min(max_width),
+            None => width,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Pad, Width};
+use core::fmt::{Error, Formatter};
+
+/// Line values up on their decimal separator.
+///
+/// Pad to the left of the integer part so that it takes `integer_width` columns,
+/// then pad the remaining blocks to the right of the fractional part.
+/// Values without separator are treated as having no fractional part.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{AlignDecimal, PaddedValue, PanicOnExcess};
+/// let pad = AlignDecimal {
+///     separator: '.',
+///     integer_width: 3,
+/// };
+/// let padded_values: Vec<_> = ["3.14", "120.5", "7"]
+///     .iter()
+///     .map(|value| PaddedValue {
+///         value,
+///         pad,
+///         pad_block: ' ',
+///         total_width: 6,
+///         handle_excess: PanicOnExcess,
+///     })
+///     .map(|x| x.to_string())
+///     .collect();
+/// assert_eq!(padded_values, ["  3.14", "120.5 ", "  7   "]);
+/// ```
+///
+/// _See also:_ [`DecimalColumn`](crate::DecimalColumn) _(requires `std` feature)_,
+/// which computes `integer_width` and `total_width` of a whole column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlignDecimal {
+    /// Character that separates the integer part from the fractional part.
+    pub separator: char,
+    /// Width of the integer part, including the pad.
+    pub integer_width: usize,
+}
+
+impl Default for AlignDecimal {
+    fn default() -> Self {
+        AlignDecimal {
+            separator: '.',
+            integer_width: 0,
+        }
+    }
+}
+
+impl<Value: Width, PadBlock: crate::PadBlock> Pad<Value, PadBlock> for AlignDecimal {
+    fn fmt(
+        &self,
+        formatter: &mut Formatter<'_>,
+        value: &Value,
+        pad_block: &PadBlock,
+        pad_width: usize,
+    ) -> Result<(), Error> {
+        let (integer_width, _) = decimal_widths(value, self.separator);
+        let left = self
+            .integer_width
+            .saturating_sub(integer_width)
+            .min(pad_width);
+        let right = pad_width - left;
+        pad_block.fill_left(formatter, left)?;
+        write!(formatter, "{}", value)?;
+        pad_block.fill_right(formatter, right)
+    }
+}
+
+/// Measure the width of the integer part and the width of the fractional part
+/// (including the separator) of a value.
+///
+/// Both parts are measured by [`Width::for_each_char_width`] so that they add up to `value.width()`.
+pub(crate) fn decimal_widths<Value>(value: &Value, separator: char) -> (usize, usize)
+where
+    Value: Width + ?Sized,
+{
+    let mut integer_width = 0;
+    let mut fraction_width = 0;
+    let mut in_fraction = false;
+    let _ = value.for_each_char_width(&mut |character, width| {
+        let width = width.unwrap_or(0);
+        if character == separator {
+            in_fraction = true;
+        }
+        if in_fraction {
+            fraction_width += width;
+        } else {
+            integer_width += width;
+        }
+        Ok(())
+    });
+    (integer_width, fraction_width)
+}
@@ -0,0 +1,173 @@
+use crate::{Excess, Width};
+use core::fmt::{self, Display, Formatter};
+
+/// Error that occurs when the width of a value exceeds the total width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExcessError {
+    /// The width of the value that caused the excess.
+    pub value_width: usize,
+    /// The total width that was exceeded by the value.
+    pub total_width: usize,
+}
+
+impl<'a, Value, PadBlock> From<Excess<'a, Value, PadBlock>> for ExcessError
+where
+    Value: Width,
+    PadBlock: crate::PadBlock,
+{
+    fn from(excess: Excess<'a, Value, PadBlock>) -> Self {
+        ExcessError {
+            value_width: excess.value_width,
+            total_width: excess.total_width,
+        }
+    }
+}
+
+impl Display for ExcessError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            formatter,
+            "value's width ({}) is greater than total_width ({})",
+            self.value_width, self.total_width,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExcessError {}
+
+/// Error that occurs when rendering a padded value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderError {
+    /// The width of the value exceeds the total width.
+    Excess(ExcessError),
+    /// The writer failed.
+    Fmt(fmt::Error),
+}
+
+impl From<ExcessError> for RenderError {
+    fn from(error: ExcessError) -> Self {
+        RenderError::Excess(error)
+    }
+}
+
+impl From<fmt::Error> for RenderError {
+    fn from(error: fmt::Error) -> Self {
+        RenderError::Fmt(error)
+    }
+}
+
+impl Display for RenderError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            RenderError::Excess(error) => write!(formatter, "{}", error),
+            RenderError::Fmt(error) => write!(formatter, "{}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RenderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RenderError::Excess(error) => Some(error),
+            RenderError::Fmt(error) => Some(error),
+        }
+    }
+}
+
+/// Error that occurs when the width of a value in a column exceeds the total width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnExcessError {
+    /// The index of the value that caused the excess.
+    pub index: usize,
+    /// Information about the excess.
+    pub excess: ExcessError,
+}
+
+impl Display for ColumnExcessError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(formatter, "row {}: {}", self.index, self.excess)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ColumnExcessError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.excess)
+    }
+}
+
+/// Error that occurs when a value is added to a column whose buffer is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<Value> {
+    /// The value that was rejected.
+    pub value: Value,
+    /// The capacity of the buffer.
+    pub capacity: usize,
+}
+
+impl<Value> Display for CapacityError<Value> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            formatter,
+            "column buffer is full (capacity: {})",
+            self.capacity,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Value: fmt::Debug> std::error::Error for CapacityError<Value> {}
+
+/// Error that occurs when parsing an unknown name of [`Alignment`](crate::Alignment).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseAlignmentError;
+
+impl Display for ParseAlignmentError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(formatter, "unknown alignment")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseAlignmentError {}
+
+/// Error that occurs when parsing a [`FormatSpec`](crate::FormatSpec).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseFormatSpecError {
+    /// The width is missing.
+    MissingWidth,
+    /// The width does not fit in [`usize`].
+    WidthOverflow,
+    /// A character is not expected at its position.
+    UnexpectedCharacter(char),
+}
+
+impl Display for ParseFormatSpecError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            ParseFormatSpecError::MissingWidth => write!(formatter, "missing width"),
+            ParseFormatSpecError::WidthOverflow => write!(formatter, "width is too large"),
+            ParseFormatSpecError::UnexpectedCharacter(character) => {
+                write!(formatter, "unexpected character {:?}", character)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFormatSpecError {}
+
+/// Error that occurs when parsing an unknown name of [`TableStyle`](crate::TableStyle).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseTableStyleError;
+
+impl Display for ParseTableStyleError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(formatter, "unknown table style")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTableStyleError {}
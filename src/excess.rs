@@ -1,5 +1,5 @@
-use crate::{Unit, Width};
-use core::fmt::{Display, Error, Formatter};
+use crate::{truncate, ExcessError, Unit, Width};
+use core::fmt::{Error, Formatter};
 use derive_more::{AsMut, AsRef, Deref, DerefMut, From};
 
 /// Information about a situation where `total_width` is less than `value.width()`.
@@ -9,7 +9,7 @@ use derive_more::{AsMut, AsRef, Deref, DerefMut, From};
 pub struct Excess<'a, Value, PadBlock = char>
 where
     Value: Width,
-    PadBlock: Display,
+    PadBlock: crate::PadBlock,
 {
     /// The value the caused the excess.
     pub value: &'a Value,
@@ -23,33 +23,40 @@ where
 
 /// What to do when the width of the value exceeds total.
 ///
-/// **Example:** Truncate to make it fit
+/// For pre-defined handlers that truncate values, see [`TruncateStart`], [`TruncateEnd`] and [`TruncateMiddle`].
+/// They cut a value at the columns that its own [`Width`] counts (see [`Width::for_each_char_width`]),
+/// so the kept part is measured the same way as the whole value:
+/// characters are never split, and pad blocks take the place of a character that would cross the boundary.
+///
+/// **Example:** Truncate to make it fit, then mark the value as truncated
 ///
 /// ```
 /// # use pretty_assertions::assert_eq;
-/// use zero_copy_pads::{ExcessHandler, Excess, PaddedValue, AlignRight};
+/// use zero_copy_pads::{ExcessHandler, Excess, PaddedValue, AlignRight, TruncateEnd};
 /// use std::fmt::{Formatter, Result};
-/// struct TruncateExcessiveString;
-/// impl ExcessHandler<&str> for TruncateExcessiveString {
+/// struct MarkTruncated;
+/// impl ExcessHandler<&str> for MarkTruncated {
 ///     fn handle_excess(&self, excess: Excess<&str>, formatter: &mut Formatter<'_>) -> Result {
-///         let mut value = excess.value.to_string();
-///         value.truncate(excess.total_width);
-///         write!(formatter, "{}", value)
+///         let total_width = excess.total_width.saturating_sub(1);
+///         TruncateEnd.handle_excess(Excess { total_width, ..excess }, formatter)?;
+///         write!(formatter, "!")
 ///     }
 /// }
-/// let padded_value = PaddedValue {
-///     handle_excess: TruncateExcessiveString,
-///     value: "abcdefghi",
-///     total_width: 4,
+/// let padded_value = |value, total_width| PaddedValue {
+///     handle_excess: MarkTruncated,
+///     value,
+///     total_width,
 ///     pad_block: ' ',
 ///     pad: AlignRight,
 /// };
-/// assert_eq!(padded_value.to_string(), "abcd");
+/// assert_eq!(padded_value("abcdefghi", 4).to_string(), "abc!");
+/// assert_eq!(padded_value("中文字符", 5).to_string(), "中文!");
+/// assert_eq!(padded_value("中文字符", 4).to_string(), "中 !");
 /// ```
 pub trait ExcessHandler<Value, PadBlock = char>
 where
     Value: Width,
-    PadBlock: Display,
+    PadBlock: crate::PadBlock,
 {
     /// Handle excessive width of a value.
     fn handle_excess(
@@ -59,30 +66,129 @@ where
     ) -> Result<(), Error>;
 }
 
+/// Where to place the marker of [`Ellipsize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EllipsisPosition {
+    /// Replace the beginning of the value with the marker.
+    Start,
+    /// Replace the middle of the value with the marker.
+    Middle,
+    /// Replace the end of the value with the marker.
+    End,
+}
+
+/// Replace the excessive part of `value` with a marker such as `…` or `...`.
+///
+/// The value is cut the same way [`TruncateStart`], [`TruncateMiddle`]
+/// and [`TruncateEnd`] do, leaving room for the [`Width`] of `marker`.
+/// If `marker` is wider than `total_width`, the value is truncated without it.
+///
+/// **When `value.width()` is not greater than `total_width`,
+/// add pads as usual:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{PaddedValue, AlignLeft, Ellipsize, EllipsisPosition};
+/// let padded_value = PaddedValue {
+///     handle_excess: Ellipsize {
+///         marker: "…",
+///         position: EllipsisPosition::Middle,
+///     },
+///     value: "/home/user/file.rs",
+///     pad_block: ' ',
+///     total_width: 20,
+///     pad: AlignLeft,
+/// };
+/// assert_eq!(padded_value.to_string(), "/home/user/file.rs  ");
+/// ```
+///
+/// **When `value.width()` is greater than `total_width`,
+/// replace the excessive part with the marker:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{PaddedValue, AlignLeft, Ellipsize, EllipsisPosition};
+/// let padded_value = PaddedValue {
+///     handle_excess: Ellipsize {
+///         marker: "…",
+///         position: EllipsisPosition::Middle,
+///     },
+///     value: "/home/user/projects/zero-copy-pads/src/lib.rs",
+///     pad_block: ' ',
+///     total_width: 21,
+///     pad: AlignLeft,
+/// };
+/// assert_eq!(padded_value.to_string(), "/home/user…src/lib.rs");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ellipsize<Marker = &'static str>
+where
+    Marker: Width,
+{
+    /// Value to take the place of the excessive part.
+    pub marker: Marker,
+    /// Where to place the marker.
+    pub position: EllipsisPosition,
+}
+
+impl Default for Ellipsize {
+    /// Replace the end of excessive values with `…`.
+    fn default() -> Self {
+        Ellipsize {
+            marker: "…",
+            position: EllipsisPosition::End,
+        }
+    }
+}
+
+impl<Value, PadBlock, Marker> ExcessHandler<Value, PadBlock> for Ellipsize<Marker>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock,
+    Marker: Width,
+{
+    fn handle_excess(
+        &self,
+        excess: Excess<Value, PadBlock>,
+        formatter: &mut Formatter<'_>,
+    ) -> Result<(), Error> {
+        truncate::ellipsize(
+            formatter,
+            excess.value,
+            excess.pad_block,
+            excess.total_width,
+            &self.marker,
+            self.position,
+        )
+    }
+}
+
 type ExcessHandlingFunctionInner<Value, PadBlock> =
     fn(Excess<Value, PadBlock>, &mut Formatter<'_>) -> Result<(), Error>;
 
 /// Turn a function (without closure) into a [`ExcessHandler`].
 ///
-/// **Example:** Truncate to make it fit
+/// **Example:** Truncate to make it fit, then mark the value as truncated
 ///
 /// ```
 /// # use pretty_assertions::assert_eq;
-/// use zero_copy_pads::{ExcessHandlingFunction, Excess, PaddedValue, AlignRight};
+/// use zero_copy_pads::{ExcessHandler, ExcessHandlingFunction, Excess, PaddedValue, AlignRight, TruncateEnd};
 /// use std::fmt::{Formatter, Result};
-/// let truncate = ExcessHandlingFunction::<&str>(|excess, formatter| {
-///     let mut value = excess.value.to_string();
-///     value.truncate(excess.total_width);
-///     write!(formatter, "{}", value)
+/// let mark_truncated = ExcessHandlingFunction::<&str>(|excess, formatter| {
+///     let total_width = excess.total_width.saturating_sub(1);
+///     TruncateEnd.handle_excess(Excess { total_width, ..excess }, formatter)?;
+///     write!(formatter, "!")
 /// });
-/// let padded_value = PaddedValue {
-///     handle_excess: truncate,
-///     value: "abcdefghi",
-///     total_width: 4,
+/// let padded_value = |value, total_width| PaddedValue {
+///     handle_excess: mark_truncated,
+///     value,
+///     total_width,
 ///     pad_block: ' ',
 ///     pad: AlignRight,
 /// };
-/// assert_eq!(padded_value.to_string(), "abcd");
+/// assert_eq!(padded_value("abcdefghi", 4).to_string(), "abc!");
+/// assert_eq!(padded_value("中文字符", 5).to_string(), "中文!");
+/// assert_eq!(padded_value("中文字符", 4).to_string(), "中 !");
 /// ```
 #[derive(Clone, Copy, AsMut, AsRef, Deref, DerefMut, From)]
 pub struct ExcessHandlingFunction<Value, PadBlock = char>(
@@ -90,12 +196,12 @@ pub struct ExcessHandlingFunction<Value, PadBlock = char>(
 )
 where
     Value: Width,
-    PadBlock: Display;
+    PadBlock: crate::PadBlock;
 
 impl<Value, PadBlock> ExcessHandler<Value, PadBlock> for ExcessHandlingFunction<Value, PadBlock>
 where
     Value: Width,
-    PadBlock: Display,
+    PadBlock: crate::PadBlock,
 {
     fn handle_excess(
         &self,
@@ -110,7 +216,7 @@ where
 pub trait UnitExcessHandler<Value, PadBlock = char>: Unit + ExcessHandler<Value, PadBlock>
 where
     Value: Width,
-    PadBlock: Display,
+    PadBlock: crate::PadBlock,
 {
 }
 
@@ -130,12 +236,12 @@ macro_rules! preset {
             const VALUE: Self = $struct_name;
         }
 
-        impl<Value: Width, PadBlock: Display> UnitExcessHandler<Value, PadBlock> for $struct_name {}
+        impl<Value: Width, PadBlock: crate::PadBlock> UnitExcessHandler<Value, PadBlock> for $struct_name {}
 
         impl<Value, PadBlock> ExcessHandler<Value, PadBlock> for $struct_name
         where
             Value: Width,
-            PadBlock: Display,
+            PadBlock: crate::PadBlock,
         {
             fn handle_excess(
                 &self,
@@ -150,7 +256,7 @@ macro_rules! preset {
         impl<Value, PadBlock> From<$struct_name> for ExcessHandlingFunction<Value, PadBlock>
         where
             Value: Width,
-            PadBlock: Display,
+            PadBlock: crate::PadBlock,
         {
             fn from(_: $struct_name) -> Self {
                 ExcessHandlingFunction(|excess, formatter| {
@@ -163,7 +269,7 @@ macro_rules! preset {
         pub fn $fn_name<Value, PadBlock>() -> ExcessHandlingFunction<Value, PadBlock>
         where
             Value: Width,
-            PadBlock: Display,
+            PadBlock: crate::PadBlock,
         {
             ExcessHandlingFunction::from($struct_name)
         }
@@ -219,6 +325,9 @@ preset! {
 
     /// Forbid all excesses, raise `fmt::Error` once encounter one.
     ///
+    /// To tell an excess apart from an error of the writer,
+    /// use [`PaddedValue::try_render`](crate::PaddedValue::try_render) instead.
+    ///
     /// **When `value.width()` is not greater than `total_width`,
     /// add pads as usual:**
     ///
@@ -263,10 +372,7 @@ preset! {
 }
 
 preset! {
-    impl |excess, _| panic!(
-        "value's width ({}) is greater than total_width ({})",
-        excess.value_width, excess.total_width,
-    );
+    impl |excess, _| panic!("{}", ExcessError::from(excess));
 
     /// Forbid all excesses, panic once encounter one.
     ///
@@ -307,3 +413,182 @@ preset! {
     /// see [`PanicOnExcess`].
     fn panic_on_excess;
 }
+
+preset! {
+    impl |excess, formatter| truncate::truncate_start(
+        formatter,
+        excess.value,
+        excess.pad_block,
+        excess.total_width,
+    );
+
+    /// Remove the beginning of `value` so that the rest fits in `total_width`.
+    ///
+    /// **When `value.width()` is not greater than `total_width`,
+    /// add pads as usual:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{PaddedValue, AlignRight, TruncateStart};
+    /// let padded_value = PaddedValue {
+    ///     handle_excess: TruncateStart,
+    ///     value: "abcdef",
+    ///     pad_block: '-',
+    ///     total_width: 9,
+    ///     pad: AlignRight,
+    /// };
+    /// assert_eq!(padded_value.to_string(), "---abcdef");
+    /// ```
+    ///
+    /// **When `value.width()` is greater than `total_width`,
+    /// display only the last `total_width` columns of `value`:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{PaddedValue, AlignRight, TruncateStart};
+    /// let padded_value = PaddedValue {
+    ///     handle_excess: TruncateStart,
+    ///     value: "abcdefghijkl",
+    ///     pad_block: '-',
+    ///     total_width: 9,
+    ///     pad: AlignRight,
+    /// };
+    /// assert_eq!(padded_value.to_string(), "defghijkl");
+    /// ```
+    ///
+    /// **When a wide character crosses the boundary, replace its remaining part with pad blocks:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{PaddedValue, AlignRight, TruncateStart};
+    /// let padded_value = PaddedValue {
+    ///     handle_excess: TruncateStart,
+    ///     value: "中文字",
+    ///     pad_block: '-',
+    ///     total_width: 3,
+    ///     pad: AlignRight,
+    /// };
+    /// assert_eq!(padded_value.to_string(), "-字");
+    /// ```
+    struct TruncateStart;
+
+    /// Create a [`ExcessHandlingFunction`] that removes the beginning of excessive values.
+    ///
+    /// see [`TruncateStart`].
+    fn truncate_start;
+}
+
+preset! {
+    impl |excess, formatter| truncate::truncate_end(
+        formatter,
+        excess.value,
+        excess.pad_block,
+        excess.total_width,
+    );
+
+    /// Remove the end of `value` so that the rest fits in `total_width`.
+    ///
+    /// **When `value.width()` is not greater than `total_width`,
+    /// add pads as usual:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{PaddedValue, AlignRight, TruncateEnd};
+    /// let padded_value = PaddedValue {
+    ///     handle_excess: TruncateEnd,
+    ///     value: "abcdef",
+    ///     pad_block: '-',
+    ///     total_width: 9,
+    ///     pad: AlignRight,
+    /// };
+    /// assert_eq!(padded_value.to_string(), "---abcdef");
+    /// ```
+    ///
+    /// **When `value.width()` is greater than `total_width`,
+    /// display only the first `total_width` columns of `value`:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{PaddedValue, AlignRight, TruncateEnd};
+    /// let padded_value = PaddedValue {
+    ///     handle_excess: TruncateEnd,
+    ///     value: "abcdefghijkl",
+    ///     pad_block: '-',
+    ///     total_width: 9,
+    ///     pad: AlignRight,
+    /// };
+    /// assert_eq!(padded_value.to_string(), "abcdefghi");
+    /// ```
+    ///
+    /// **When a wide character crosses the boundary, replace its remaining part with pad blocks:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{PaddedValue, AlignRight, TruncateEnd};
+    /// let padded_value = PaddedValue {
+    ///     handle_excess: TruncateEnd,
+    ///     value: "中文字",
+    ///     pad_block: '-',
+    ///     total_width: 3,
+    ///     pad: AlignRight,
+    /// };
+    /// assert_eq!(padded_value.to_string(), "中-");
+    /// ```
+    struct TruncateEnd;
+
+    /// Create a [`ExcessHandlingFunction`] that removes the end of excessive values.
+    ///
+    /// see [`TruncateEnd`].
+    fn truncate_end;
+}
+
+preset! {
+    impl |excess, formatter| truncate::truncate_middle(
+        formatter,
+        excess.value,
+        excess.pad_block,
+        excess.total_width,
+    );
+
+    /// Remove the middle of `value` so that the rest fits in `total_width`.
+    ///
+    /// The beginning of `value` takes the remainder column (if any).
+    ///
+    /// **When `value.width()` is not greater than `total_width`,
+    /// add pads as usual:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{PaddedValue, AlignRight, TruncateMiddle};
+    /// let padded_value = PaddedValue {
+    ///     handle_excess: TruncateMiddle,
+    ///     value: "abcdef",
+    ///     pad_block: '-',
+    ///     total_width: 9,
+    ///     pad: AlignRight,
+    /// };
+    /// assert_eq!(padded_value.to_string(), "---abcdef");
+    /// ```
+    ///
+    /// **When `value.width()` is greater than `total_width`,
+    /// display only the first and the last columns of `value`:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{PaddedValue, AlignRight, TruncateMiddle};
+    /// let padded_value = PaddedValue {
+    ///     handle_excess: TruncateMiddle,
+    ///     value: "abcdefghijkl",
+    ///     pad_block: '-',
+    ///     total_width: 9,
+    ///     pad: AlignRight,
+    /// };
+    /// assert_eq!(padded_value.to_string(), "abcdeijkl");
+    /// ```
+    struct TruncateMiddle;
+
+    /// Create a [`ExcessHandlingFunction`] that removes the middle of excessive values.
+    ///
+    /// see [`TruncateMiddle`].
+    fn truncate_middle;
+}
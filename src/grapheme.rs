@@ -0,0 +1,46 @@
+#![cfg(feature = "grapheme")]
+
+use crate::CharWidthCallback;
+use core::fmt::Error;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+
+/// Check if a character is one of the regional indicator symbols that make up flags.
+fn is_regional_indicator(character: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&character)
+}
+
+/// Width of a single extended grapheme cluster as rendered by a modern terminal.
+fn grapheme_cluster_width(cluster: &str) -> usize {
+    let mut chars = cluster.chars();
+    let first = match chars.next() {
+        Some(first) => first,
+        None => return 0,
+    };
+    let mut width = UnicodeWidthChar::width(first).unwrap_or(0);
+    for character in chars {
+        match character {
+            '\u{FE0E}' => width = 1,
+            '\u{FE0F}' => width = 2,
+            _ if is_regional_indicator(first) && is_regional_indicator(character) => width = 2,
+            _ => {}
+        }
+    }
+    width
+}
+
+/// Sum of widths of every extended grapheme cluster of a text.
+pub(crate) fn grapheme_width(text: &str) -> usize {
+    text.graphemes(true).map(grapheme_cluster_width).sum()
+}
+
+/// Report the characters of a text to [`Width::for_each_char_width`](crate::Width::for_each_char_width),
+/// the first character of a cluster takes the width of the whole cluster.
+pub(crate) fn grapheme_char_widths(text: &str, callback: CharWidthCallback) -> Result<(), Error> {
+    text.graphemes(true).try_for_each(|cluster| {
+        let mut width = Some(grapheme_cluster_width(cluster));
+        cluster
+            .chars()
+            .try_for_each(|character| callback(character, width.take().or(Some(0))))
+    })
+}
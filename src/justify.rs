@@ -0,0 +1,309 @@
+use crate::{
+    truncate, CharWidthCallback, EllipsisPosition, Excess, ExcessHandler, Pad, Unit, UnitPad, Width,
+};
+use core::fmt::{Display, Error, Formatter, Write};
+
+/// Two values to be placed at both ends of a line by [`JustifyBetween`].
+///
+/// **Key traits:**
+/// * [`Display`]: Displays `left` then `right`.
+/// * [`Width`]: Sum of the widths of `left` and `right`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pair<Left, Right>
+where
+    Left: Width,
+    Right: Width,
+{
+    /// Value at the start of the line.
+    pub left: Left,
+    /// Value at the end of the line.
+    pub right: Right,
+}
+
+impl<Left, Right> Display for Pair<Left, Right>
+where
+    Left: Width,
+    Right: Width,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(formatter, "{}{}", self.left, self.right)
+    }
+}
+
+impl<Left, Right> Width for Pair<Left, Right>
+where
+    Left: Width,
+    Right: Width,
+{
+    fn width(&self) -> usize {
+        self.left.width() + self.right.width()
+    }
+
+    fn for_each_char_width(&self, callback: CharWidthCallback<'_>) -> Result<(), Error> {
+        self.left.for_each_char_width(callback)?;
+        self.right.for_each_char_width(callback)
+    }
+}
+
+/// Place the two values of a [`Pair`] at both ends, fill the gap between them with the pad.
+///
+/// **Example:** Table of contents
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{JustifyBetween, Pair, PaddedValue, ShrinkLeft};
+/// let entries = [("Introduction", "3"), ("Getting started", "12"), ("API", "120")];
+/// let lines: Vec<_> = entries
+///     .iter()
+///     .map(|(title, page)| PaddedValue {
+///         value: Pair { left: *title, right: *page },
+///         pad_block: '.',
+///         total_width: 20,
+///         pad: JustifyBetween,
+///         handle_excess: ShrinkLeft::default(),
+///     })
+///     .map(|x| x.to_string())
+///     .collect();
+/// let expected = [
+///     "Introduction.......3",
+///     "Getting started...12",
+///     "API..............120",
+/// ];
+/// assert_eq!(lines, expected);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct JustifyBetween;
+
+impl Unit for JustifyBetween {
+    const VALUE: Self = JustifyBetween;
+}
+
+impl<Left, Right, PadBlock> UnitPad<Pair<Left, Right>, PadBlock> for JustifyBetween
+where
+    Left: Width,
+    Right: Width,
+    PadBlock: crate::PadBlock,
+{
+}
+
+impl<Left, Right, PadBlock> Pad<Pair<Left, Right>, PadBlock> for JustifyBetween
+where
+    Left: Width,
+    Right: Width,
+    PadBlock: crate::PadBlock,
+{
+    fn fmt(
+        &self,
+        formatter: &mut Formatter<'_>,
+        value: &Pair<Left, Right>,
+        pad_block: &PadBlock,
+        pad_width: usize,
+    ) -> Result<(), Error> {
+        write!(formatter, "{}", value.left)?;
+        pad_block.fill(formatter, pad_width)?;
+        write!(formatter, "{}", value.right)
+    }
+}
+
+/// Keep the right value of a [`Pair`] intact, shorten the left value with a marker.
+///
+/// If the right value alone exceeds `total_width`, the whole pair is truncated from the end.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{JustifyBetween, Pair, PaddedValue, ShrinkLeft};
+/// let padded_value = PaddedValue {
+///     value: Pair { left: "A very long chapter title", right: "42" },
+///     pad_block: '.',
+///     total_width: 16,
+///     pad: JustifyBetween,
+///     handle_excess: ShrinkLeft { marker: "..." },
+/// };
+/// assert_eq!(padded_value.to_string(), "A very long...42");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShrinkLeft<Marker = &'static str>
+where
+    Marker: Width,
+{
+    /// Value to take the place of the excessive part of the left value.
+    pub marker: Marker,
+}
+
+impl Default for ShrinkLeft {
+    /// Replace the end of excessive left values with `…`.
+    fn default() -> Self {
+        ShrinkLeft { marker: "…" }
+    }
+}
+
+impl<Left, Right, PadBlock, Marker> ExcessHandler<Pair<Left, Right>, PadBlock>
+    for ShrinkLeft<Marker>
+where
+    Left: Width,
+    Right: Width,
+    PadBlock: crate::PadBlock,
+    Marker: Width,
+{
+    fn handle_excess(
+        &self,
+        excess: Excess<Pair<Left, Right>, PadBlock>,
+        formatter: &mut Formatter<'_>,
+    ) -> Result<(), Error> {
+        let Pair { left, right } = excess.value;
+        let right_width = right.width();
+        if right_width > excess.total_width {
+            return truncate::truncate_end(
+                formatter,
+                excess.value,
+                excess.pad_block,
+                excess.total_width,
+            );
+        }
+        truncate::ellipsize(
+            formatter,
+            left,
+            excess.pad_block,
+            excess.total_width - right_width,
+            &self.marker,
+            EllipsisPosition::End,
+        )?;
+        write!(formatter, "{}", right)
+    }
+}
+
+/// Distribute the pad across the gaps between words, give the remainder blocks to the leftmost gaps.
+///
+/// Words are separated by whitespace characters.
+/// Values without gaps between words are padded to the right.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{JustifyBiasLeft, PaddedValue, PanicOnExcess};
+/// let padded_value = PaddedValue {
+///     pad: JustifyBiasLeft,
+///     value: "a quick brown fox",
+///     pad_block: '-',
+///     total_width: 22,
+///     handle_excess: PanicOnExcess,
+/// };
+/// assert_eq!(padded_value.to_string(), "a --quick --brown -fox");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct JustifyBiasLeft;
+
+/// Distribute the pad across the gaps between words, give the remainder blocks to the rightmost gaps.
+///
+/// Words are separated by whitespace characters.
+/// Values without gaps between words are padded to the right.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{JustifyBiasRight, PaddedValue, PanicOnExcess};
+/// let padded_value = PaddedValue {
+///     pad: JustifyBiasRight,
+///     value: "a quick brown fox",
+///     pad_block: '-',
+///     total_width: 22,
+///     handle_excess: PanicOnExcess,
+/// };
+/// assert_eq!(padded_value.to_string(), "a -quick --brown --fox");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct JustifyBiasRight;
+
+macro_rules! justify_pad {
+    ($name:ident, $left_biased:expr) => {
+        impl Unit for $name {
+            const VALUE: Self = $name;
+        }
+
+        impl<Value: Width, PadBlock: crate::PadBlock> UnitPad<Value, PadBlock> for $name {}
+
+        impl<Value: Width, PadBlock: crate::PadBlock> Pad<Value, PadBlock> for $name {
+            fn fmt(
+                &self,
+                formatter: &mut Formatter<'_>,
+                value: &Value,
+                pad_block: &PadBlock,
+                pad_width: usize,
+            ) -> Result<(), Error> {
+                justify(formatter, value, pad_block, pad_width, $left_biased)
+            }
+        }
+    };
+}
+
+justify_pad!(JustifyBiasLeft, true);
+justify_pad!(JustifyBiasRight, false);
+
+/// Track the gaps between the words of a value, one visible character at a time.
+#[derive(Debug, Default)]
+struct GapTracker {
+    seen_word: bool,
+    in_gap: bool,
+}
+
+impl GapTracker {
+    /// Feed the next visible character, return `true` if it closes a gap.
+    fn closes_gap(&mut self, character: char) -> bool {
+        if character.is_whitespace() {
+            self.in_gap = self.seen_word;
+            return false;
+        }
+        let closes_gap = self.in_gap;
+        self.seen_word = true;
+        self.in_gap = false;
+        closes_gap
+    }
+}
+
+/// Write a value with `pad_width` blocks distributed across the gaps between its words.
+///
+/// Words and gaps are found among the measured characters of [`Width::for_each_char_width`].
+fn justify<Value, PadBlock>(
+    formatter: &mut Formatter<'_>,
+    value: &Value,
+    pad_block: &PadBlock,
+    pad_width: usize,
+    left_biased: bool,
+) -> Result<(), Error>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock,
+{
+    let mut gap_count = 0;
+    let mut tracker = GapTracker::default();
+    value.for_each_char_width(&mut |character, width| {
+        if width.is_some() && tracker.closes_gap(character) {
+            gap_count += 1;
+        }
+        Ok(())
+    })?;
+    if gap_count == 0 {
+        write!(formatter, "{}", value)?;
+        return pad_block.fill_right(formatter, pad_width);
+    }
+    let base = pad_width / gap_count;
+    let remainder = pad_width % gap_count;
+    let mut gap_index = 0;
+    let mut tracker = GapTracker::default();
+    value.for_each_char_width(&mut |character, width| {
+        if width.is_some() && tracker.closes_gap(character) {
+            let extra = if left_biased {
+                gap_index < remainder
+            } else {
+                gap_index >= gap_count - remainder
+            };
+            pad_block.fill(formatter, base + extra as usize)?;
+            gap_index += 1;
+        }
+        formatter.write_char(character)
+    })
+}
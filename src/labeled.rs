@@ -0,0 +1,424 @@
+#![cfg(feature = "std")]
+
+use crate::{
+    Alignment, ExcessHandler, PaddedColumnIter, PaddedValue, TruncateEnd, Width, WidthConstraint,
+};
+use core::{
+    cmp::max,
+    fmt::{Display, Error, Formatter},
+};
+use derive_builder::Builder;
+
+/// Counterpart of [`PaddedColumn`](crate::PaddedColumn) with an optional header, footer and separators.
+///
+/// The header and the footer take part in the computation of the total width,
+/// but they are padded with their own pad and pad block.
+/// The total width is limited by `width_constraint`, rows that exceed it
+/// (including the header and the footer) are handled by `handle_excess`.
+/// When `separator` is set, a separator row is placed after the header
+/// (if any) and before the footer (if any).
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`IntoIterator`]: Build an iterator of [`LabeledRow`]s.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{AlignCenterLeft, AlignRight, LabeledColumn, TruncateEnd, WidthConstraint};
+/// let labeled_column = LabeledColumn {
+///     values: [12, 3456, 78].iter(),
+///     pad_block: ' ',
+///     pad: AlignRight,
+///     header: Some("Price"),
+///     footer: Some("3546"),
+///     label_pad_block: ' ',
+///     label_pad: AlignCenterLeft,
+///     separator: Some('-'),
+///     width_constraint: WidthConstraint::default(),
+///     handle_excess: TruncateEnd,
+/// };
+/// let rows: Vec<_> = labeled_column
+///     .into_iter()
+///     .map(|x| x.to_string())
+///     .collect();
+/// let expected = [
+///     "Price",
+///     "-----",
+///     "   12",
+///     " 3456",
+///     "   78",
+///     "-----",
+///     "3546 ",
+/// ];
+/// assert_eq!(rows, expected);
+/// ```
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct LabeledColumn<
+    ValueIter,
+    Label = &'static str,
+    PadBlock = char,
+    Pad = Alignment,
+    LabelPadBlock = char,
+    LabelPad = Alignment,
+    Separator = char,
+    HandleExcess = TruncateEnd,
+> where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    Label: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+    LabelPadBlock: crate::PadBlock + Copy,
+    LabelPad: crate::Pad<Label, LabelPadBlock> + Copy,
+    Separator: crate::PadBlock + Copy,
+    HandleExcess:
+        ExcessHandler<ValueIter::Item, PadBlock> + ExcessHandler<Label, LabelPadBlock> + Copy,
+{
+    /// Values to be padded.
+    pub values: ValueIter,
+    /// Block of the pad of the values (see [`PadBlock`](crate::PadBlock)).
+    pub pad_block: PadBlock,
+    /// Where to place the pad of the values.
+    pub pad: Pad,
+    /// Row before the values.
+    pub header: Option<Label>,
+    /// Row after the values.
+    pub footer: Option<Label>,
+    /// Block of the pad of the header and the footer.
+    pub label_pad_block: LabelPadBlock,
+    /// Where to place the pad of the header and the footer.
+    pub label_pad: LabelPad,
+    /// Block that is repeated to fill the separator rows.
+    pub separator: Option<Separator>,
+    /// Limits on the total width.
+    pub width_constraint: WidthConstraint,
+    /// How to write rows whose width exceeds the total width.
+    pub handle_excess: HandleExcess,
+}
+
+/// Row of a [`LabeledColumn`] that is filled entirely with a block.
+///
+/// **Key traits:**
+/// * [`Display`]: Fill `width` columns with `block`.
+/// * [`Width`]: Return `width`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeparatorRow<Block: crate::PadBlock> {
+    /// Block to fill the row with.
+    pub block: Block,
+    /// Width of the row.
+    pub width: usize,
+}
+
+impl<Block: crate::PadBlock> Display for SeparatorRow<Block> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        self.block.fill(formatter, self.width)
+    }
+}
+
+impl<Block: crate::PadBlock> Width for SeparatorRow<Block> {
+    fn width(&self) -> usize {
+        self.width
+    }
+}
+
+/// Row yielded by [`LabeledColumnIter`].
+///
+/// **Key traits:**
+/// * [`Display`]: Display the row.
+/// * [`Width`]: Get width of the row.
+#[derive(Debug, Clone, Copy)]
+pub enum LabeledRow<Value, Label, PadBlock, Pad, LabelPadBlock, LabelPad, Separator, HandleExcess>
+where
+    Value: Width,
+    Label: Width,
+    PadBlock: crate::PadBlock,
+    Pad: crate::Pad<Value, PadBlock>,
+    LabelPadBlock: crate::PadBlock,
+    LabelPad: crate::Pad<Label, LabelPadBlock>,
+    Separator: crate::PadBlock,
+    HandleExcess: ExcessHandler<Value, PadBlock> + ExcessHandler<Label, LabelPadBlock>,
+{
+    /// The padded header.
+    Header(PaddedValue<Label, LabelPadBlock, HandleExcess, LabelPad>),
+    /// A separator after the header or before the footer.
+    Separator(SeparatorRow<Separator>),
+    /// A padded value.
+    Value(PaddedValue<Value, PadBlock, HandleExcess, Pad>),
+    /// The padded footer.
+    Footer(PaddedValue<Label, LabelPadBlock, HandleExcess, LabelPad>),
+}
+
+impl<Value, Label, PadBlock, Pad, LabelPadBlock, LabelPad, Separator, HandleExcess> Display
+    for LabeledRow<Value, Label, PadBlock, Pad, LabelPadBlock, LabelPad, Separator, HandleExcess>
+where
+    Value: Width,
+    Label: Width,
+    PadBlock: crate::PadBlock,
+    Pad: crate::Pad<Value, PadBlock>,
+    LabelPadBlock: crate::PadBlock,
+    LabelPad: crate::Pad<Label, LabelPadBlock>,
+    Separator: crate::PadBlock,
+    HandleExcess: ExcessHandler<Value, PadBlock> + ExcessHandler<Label, LabelPadBlock>,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            LabeledRow::Header(header) => write!(formatter, "{}", header),
+            LabeledRow::Separator(separator) => write!(formatter, "{}", separator),
+            LabeledRow::Value(value) => write!(formatter, "{}", value),
+            LabeledRow::Footer(footer) => write!(formatter, "{}", footer),
+        }
+    }
+}
+
+impl<Value, Label, PadBlock, Pad, LabelPadBlock, LabelPad, Separator, HandleExcess> Width
+    for LabeledRow<Value, Label, PadBlock, Pad, LabelPadBlock, LabelPad, Separator, HandleExcess>
+where
+    Value: Width,
+    Label: Width,
+    PadBlock: crate::PadBlock,
+    Pad: crate::Pad<Value, PadBlock>,
+    LabelPadBlock: crate::PadBlock,
+    LabelPad: crate::Pad<Label, LabelPadBlock>,
+    Separator: crate::PadBlock,
+    HandleExcess: ExcessHandler<Value, PadBlock> + ExcessHandler<Label, LabelPadBlock>,
+{
+    fn width(&self) -> usize {
+        match self {
+            LabeledRow::Header(header) => header.width(),
+            LabeledRow::Separator(separator) => separator.width(),
+            LabeledRow::Value(value) => value.width(),
+            LabeledRow::Footer(footer) => footer.width(),
+        }
+    }
+}
+
+impl<ValueIter, Label, PadBlock, Pad, LabelPadBlock, LabelPad, Separator, HandleExcess> IntoIterator
+    for LabeledColumn<
+        ValueIter,
+        Label,
+        PadBlock,
+        Pad,
+        LabelPadBlock,
+        LabelPad,
+        Separator,
+        HandleExcess,
+    >
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    Label: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+    LabelPadBlock: crate::PadBlock + Copy,
+    LabelPad: crate::Pad<Label, LabelPadBlock> + Copy,
+    Separator: crate::PadBlock + Copy,
+    HandleExcess:
+        ExcessHandler<ValueIter::Item, PadBlock> + ExcessHandler<Label, LabelPadBlock> + Copy,
+{
+    type Item = LabeledRow<
+        ValueIter::Item,
+        Label,
+        PadBlock,
+        Pad,
+        LabelPadBlock,
+        LabelPad,
+        Separator,
+        HandleExcess,
+    >;
+    type IntoIter = LabeledColumnIter<
+        ValueIter::Item,
+        Label,
+        PadBlock,
+        Pad,
+        LabelPadBlock,
+        LabelPad,
+        Separator,
+        HandleExcess,
+    >;
+    fn into_iter(self) -> Self::IntoIter {
+        let LabeledColumn {
+            values,
+            pad_block,
+            pad,
+            header,
+            footer,
+            label_pad_block,
+            label_pad,
+            separator,
+            width_constraint,
+            handle_excess,
+        } = self;
+        let label_width = header
+            .iter()
+            .chain(footer.iter())
+            .map(Width::width)
+            .max()
+            .unwrap_or(0);
+        let mut body = PaddedColumnIter::new(pad_block, pad)
+            .with_width_constraint(WidthConstraint {
+                min_width: max(width_constraint.min_width, label_width),
+                ..width_constraint
+            })
+            .with_excess_handler(handle_excess);
+        body.extend(values);
+        let total_width = body.total_width();
+        let pad_label = |value| PaddedValue {
+            value,
+            pad_block: label_pad_block,
+            total_width,
+            pad: label_pad,
+            handle_excess,
+        };
+        let separator_row = |exists: bool| {
+            separator.filter(|_| exists).map(|block| SeparatorRow {
+                block,
+                width: total_width,
+            })
+        };
+        LabeledColumnIter {
+            header_separator: separator_row(header.is_some()),
+            footer_separator: separator_row(footer.is_some()),
+            header: header.map(pad_label),
+            footer: footer.map(pad_label),
+            body,
+        }
+    }
+}
+
+/// Iterator created by calling [`into_iter`](IntoIterator::into_iter) on [`LabeledColumn`].
+///
+/// **Required features:** `std`
+#[derive(Debug, Clone)]
+pub struct LabeledColumnIter<
+    Value,
+    Label = &'static str,
+    PadBlock = char,
+    Pad = Alignment,
+    LabelPadBlock = char,
+    LabelPad = Alignment,
+    Separator = char,
+    HandleExcess = TruncateEnd,
+> where
+    Value: Width,
+    Label: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+    LabelPadBlock: crate::PadBlock + Copy,
+    LabelPad: crate::Pad<Label, LabelPadBlock> + Copy,
+    Separator: crate::PadBlock + Copy,
+    HandleExcess: ExcessHandler<Value, PadBlock> + ExcessHandler<Label, LabelPadBlock> + Copy,
+{
+    header: Option<PaddedValue<Label, LabelPadBlock, HandleExcess, LabelPad>>,
+    header_separator: Option<SeparatorRow<Separator>>,
+    body: PaddedColumnIter<Value, PadBlock, Pad, HandleExcess>,
+    footer_separator: Option<SeparatorRow<Separator>>,
+    footer: Option<PaddedValue<Label, LabelPadBlock, HandleExcess, LabelPad>>,
+}
+
+impl<Value, Label, PadBlock, Pad, LabelPadBlock, LabelPad, Separator, HandleExcess>
+    LabeledColumnIter<Value, Label, PadBlock, Pad, LabelPadBlock, LabelPad, Separator, HandleExcess>
+where
+    Value: Width,
+    Label: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+    LabelPadBlock: crate::PadBlock + Copy,
+    LabelPad: crate::Pad<Label, LabelPadBlock> + Copy,
+    Separator: crate::PadBlock + Copy,
+    HandleExcess: ExcessHandler<Value, PadBlock> + ExcessHandler<Label, LabelPadBlock> + Copy,
+{
+    /// Pad block of the values that was used in the construction of [`LabeledColumn`].
+    pub fn pad_block(&self) -> PadBlock {
+        self.body.pad_block()
+    }
+
+    /// Padding method of the values that was used in the construction of [`LabeledColumn`].
+    pub fn pad(&self) -> Pad {
+        self.body.pad()
+    }
+
+    /// Maximum width of all values, the header and the footer.
+    pub fn total_width(&self) -> usize {
+        self.body.total_width()
+    }
+}
+
+impl<Value, Label, PadBlock, Pad, LabelPadBlock, LabelPad, Separator, HandleExcess> Iterator
+    for LabeledColumnIter<
+        Value,
+        Label,
+        PadBlock,
+        Pad,
+        LabelPadBlock,
+        LabelPad,
+        Separator,
+        HandleExcess,
+    >
+where
+    Value: Width,
+    Label: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+    LabelPadBlock: crate::PadBlock + Copy,
+    LabelPad: crate::Pad<Label, LabelPadBlock> + Copy,
+    Separator: crate::PadBlock + Copy,
+    HandleExcess: ExcessHandler<Value, PadBlock> + ExcessHandler<Label, LabelPadBlock> + Copy,
+{
+    type Item =
+        LabeledRow<Value, Label, PadBlock, Pad, LabelPadBlock, LabelPad, Separator, HandleExcess>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(header) = self.header.take() {
+            return Some(LabeledRow::Header(header));
+        }
+        if let Some(separator) = self.header_separator.take() {
+            return Some(LabeledRow::Separator(separator));
+        }
+        if let Some(value) = self.body.next() {
+            return Some(LabeledRow::Value(value));
+        }
+        if let Some(separator) = self.footer_separator.take() {
+            return Some(LabeledRow::Separator(separator));
+        }
+        self.footer.take().map(LabeledRow::Footer)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<Value, Label, PadBlock, Pad, LabelPadBlock, LabelPad, Separator, HandleExcess>
+    ExactSizeIterator
+    for LabeledColumnIter<
+        Value,
+        Label,
+        PadBlock,
+        Pad,
+        LabelPadBlock,
+        LabelPad,
+        Separator,
+        HandleExcess,
+    >
+where
+    Value: Width,
+    Label: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+    LabelPadBlock: crate::PadBlock + Copy,
+    LabelPad: crate::Pad<Label, LabelPadBlock> + Copy,
+    Separator: crate::PadBlock + Copy,
+    HandleExcess: ExcessHandler<Value, PadBlock> + ExcessHandler<Label, LabelPadBlock> + Copy,
+{
+    fn len(&self) -> usize {
+        self.header.is_some() as usize
+            + self.header_separator.is_some() as usize
+            + self.body.len()
+            + self.footer_separator.is_some() as usize
+            + self.footer.is_some() as usize
+    }
+}
@@ -0,0 +1,535 @@
+#![cfg(feature = "std")]
+
+use crate::{AlignLeft, Alignment, ExcessPolicy, PaddedValue, Width, WrappedLine, WrappedValue};
+use derive_builder::Builder;
+use std::{
+    collections::LinkedList,
+    fmt::{Display, Error, Formatter},
+};
+
+/// How to display a value that is wider than its column after the column was shrunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShrinkPolicy {
+    /// Cut off the end of the value (see [`TruncateEnd`](crate::TruncateEnd)).
+    Truncate,
+    /// Replace the end of the value with `…` (see [`Ellipsize`](crate::Ellipsize)).
+    Ellipsize,
+    /// Wrap the value onto multiple lines (see [`WrappedValue`]).
+    Wrap,
+}
+
+impl ShrinkPolicy {
+    /// How to handle the excess of a single line.
+    fn excess_policy(self) -> ExcessPolicy {
+        match self {
+            ShrinkPolicy::Truncate | ShrinkPolicy::Wrap => ExcessPolicy::TruncateEnd,
+            ShrinkPolicy::Ellipsize => ExcessPolicy::Ellipsize,
+        }
+    }
+}
+
+impl Default for ShrinkPolicy {
+    /// Cut off the end of the value (see [`TruncateEnd`](crate::TruncateEnd)).
+    fn default() -> Self {
+        ShrinkPolicy::Truncate
+    }
+}
+
+/// Layout settings of a column of [`LayoutTable`].
+///
+/// **Required features:** `std`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnLayout<Pad = Alignment> {
+    /// Where to place the pad.
+    pub pad: Pad,
+    /// Width that the column is never shrunk below, a column is never shrunk below 1 either.
+    ///
+    /// Columns whose values are all narrower than this are not widened.
+    pub min_width: usize,
+    /// Columns with lower priority are shrunk first.
+    pub priority: usize,
+    /// How to display values that are wider than the shrunk column.
+    pub shrink: ShrinkPolicy,
+}
+
+/// Layout settings of [`LayoutTable`].
+///
+/// **Required features:** `std`
+///
+/// Columns that have no corresponding item in `columns` use `ColumnLayout::default()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableLayout<Pad = Alignment, Separator = &'static str>
+where
+    Separator: Width + Copy,
+{
+    /// Layout settings of each column.
+    pub columns: Vec<ColumnLayout<Pad>>,
+    /// Value to be placed between every two adjacent cells of a row.
+    pub separator: Separator,
+    /// Width that a row should fit in, such as the width of a terminal.
+    pub target_width: usize,
+}
+
+impl<Pad, Separator> TableLayout<Pad, Separator>
+where
+    Pad: Default + Copy,
+    Separator: Width + Copy,
+{
+    /// Layout settings of the column at `index`.
+    pub fn column(&self, index: usize) -> ColumnLayout<Pad> {
+        self.columns.get(index).copied().unwrap_or_default()
+    }
+
+    /// Compute the width of each column from the maximum width of its values.
+    ///
+    /// When the natural widths and the separators do not fit in `target_width`,
+    /// columns are shrunk one priority level at a time, starting from the lowest.
+    /// Within a level, the widest columns are shrunk first.
+    /// A column is never shrunk below its `min_width` nor below 1.
+    /// If every column has reached that limit, the result is wider than `target_width`.
+    ///
+    /// **Example:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{ColumnLayout, TableLayout};
+    /// let layout: TableLayout = TableLayout {
+    ///     columns: vec![
+    ///         ColumnLayout { priority: 1, ..ColumnLayout::default() },
+    ///         ColumnLayout { min_width: 4, ..ColumnLayout::default() },
+    ///         ColumnLayout::default(),
+    ///     ],
+    ///     separator: " ",
+    ///     target_width: 20,
+    /// };
+    /// assert_eq!(layout.solve(&[6, 10, 8]), vec![6, 6, 6]);
+    /// assert_eq!(layout.solve(&[6, 3, 3]), vec![6, 3, 3]);
+    /// assert_eq!(layout.solve(&[30, 10, 8]), vec![13, 4, 1]);
+    /// ```
+    pub fn solve(&self, natural_widths: &[usize]) -> Vec<usize> {
+        let mut widths = natural_widths.to_vec();
+        let separator_count = widths.len().saturating_sub(1);
+        let available_width = self
+            .target_width
+            .saturating_sub(separator_count * self.separator.width());
+        let mut excess = widths.iter().sum::<usize>().saturating_sub(available_width);
+        let mut priorities: Vec<usize> = (0..widths.len())
+            .map(|index| self.column(index).priority)
+            .collect();
+        priorities.sort_unstable();
+        priorities.dedup();
+        for priority in priorities {
+            if excess == 0 {
+                break;
+            }
+            let level: Vec<(usize, usize)> = (0..widths.len())
+                .map(|index| (index, self.column(index)))
+                .filter(|(_, column)| column.priority == priority)
+                .map(|(index, column)| (index, column.min_width.max(1)))
+                .collect();
+            // Total reduction when every column of the level is lowered to `cap` (but not below its floor).
+            let reduction = |cap: usize| -> usize {
+                level
+                    .iter()
+                    .map(|&(index, floor)| widths[index].saturating_sub(cap.max(floor)))
+                    .sum()
+            };
+            // Lowest cap whose reduction does not exceed the excess.
+            let (mut low, mut high) = (
+                0,
+                level
+                    .iter()
+                    .map(|&(index, _)| widths[index])
+                    .max()
+                    .unwrap_or(0),
+            );
+            while low < high {
+                let middle = low + (high - low) / 2;
+                if reduction(middle) <= excess {
+                    high = middle;
+                } else {
+                    low = middle + 1;
+                }
+            }
+            let cap = low;
+            excess -= reduction(cap);
+            for &(index, floor) in &level {
+                widths[index] = widths[index].min(cap.max(floor));
+            }
+            // Spread the remainder over the last columns that sit at the cap above their floor.
+            for &(index, floor) in level.iter().rev() {
+                if excess == 0 {
+                    break;
+                }
+                if widths[index] == cap && cap > floor {
+                    widths[index] -= 1;
+                    excess -= 1;
+                }
+            }
+        }
+        widths
+    }
+}
+
+/// Pad all values in a table and shrink its columns to fit in a target width.
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`IntoIterator`]: Build an iterator of rows.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{Alignment, ColumnLayout, LayoutTable, ShrinkPolicy, TableLayout};
+/// let rows = vec![
+///     vec!["Name", "Description", "Size"],
+///     vec!["zero-copy-pads", "Padding/aligning values", "25 KB"],
+///     vec!["fmt-iter", "Display an iterator", "8 KB"],
+/// ];
+/// let layout_table = LayoutTable {
+///     rows: rows.iter(),
+///     pad_block: ' ',
+///     layout: TableLayout {
+///         columns: vec![
+///             ColumnLayout {
+///                 priority: 1,
+///                 shrink: ShrinkPolicy::Ellipsize,
+///                 ..ColumnLayout::default()
+///             },
+///             ColumnLayout {
+///                 min_width: 8,
+///                 shrink: ShrinkPolicy::Wrap,
+///                 ..ColumnLayout::default()
+///             },
+///             ColumnLayout {
+///                 pad: Alignment::Right,
+///                 priority: 2,
+///                 ..ColumnLayout::default()
+///             },
+///         ],
+///         separator: " | ",
+///         target_width: 40,
+///     },
+/// };
+/// let iter = layout_table.into_iter();
+/// assert_eq!(iter.natural_widths(), &[14, 23, 5]);
+/// assert_eq!(iter.column_widths(), &[14, 15, 5]);
+/// let rows: Vec<_> = iter.map(|x| x.to_string()).collect();
+/// let expected = [
+///     "Name           | Description     |  Size",
+///     "zero-copy-pads | Padding/        | 25 KB\n               | aligning values |      ",
+///     "fmt-iter       | Display an      |  8 KB\n               | iterator        |      ",
+/// ];
+/// assert_eq!(rows, expected);
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct LayoutTable<RowIter, PadBlock = char, Pad = Alignment, Separator = &'static str>
+where
+    RowIter: Iterator,
+    RowIter::Item: IntoIterator,
+    <RowIter::Item as IntoIterator>::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: for<'a> crate::Pad<&'a <RowIter::Item as IntoIterator>::Item, PadBlock>
+        + crate::Pad<WrappedLine, PadBlock>
+        + Default
+        + Copy,
+    Separator: Width + Copy,
+{
+    /// Rows of values to be padded.
+    pub rows: RowIter,
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
+    pub pad_block: PadBlock,
+    /// How to lay the columns out.
+    pub layout: TableLayout<Pad, Separator>,
+}
+
+impl<RowIter, PadBlock, Pad, Separator> IntoIterator
+    for LayoutTable<RowIter, PadBlock, Pad, Separator>
+where
+    RowIter: Iterator,
+    RowIter::Item: IntoIterator,
+    <RowIter::Item as IntoIterator>::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: for<'a> crate::Pad<&'a <RowIter::Item as IntoIterator>::Item, PadBlock>
+        + crate::Pad<WrappedLine, PadBlock>
+        + Default
+        + Copy,
+    Separator: Width + Copy,
+{
+    type Item = LayoutRow<<RowIter::Item as IntoIterator>::Item, PadBlock, Pad, Separator>;
+    type IntoIter =
+        LayoutTableIter<<RowIter::Item as IntoIterator>::Item, PadBlock, Pad, Separator>;
+    fn into_iter(self) -> Self::IntoIter {
+        let LayoutTable {
+            rows,
+            pad_block,
+            layout,
+        } = self;
+        let mut natural_widths = Vec::new();
+        let row_list: LinkedList<Vec<_>> = rows
+            .map(|row| {
+                let row: Vec<_> = row.into_iter().collect();
+                for (index, value) in row.iter().enumerate() {
+                    let width = value.width();
+                    match natural_widths.get_mut(index) {
+                        Some(column_width) if *column_width < width => *column_width = width,
+                        Some(_) => {}
+                        None => natural_widths.push(width),
+                    }
+                }
+                row
+            })
+            .collect();
+        let column_widths = layout.solve(&natural_widths);
+        LayoutTableIter {
+            row_list,
+            pad_block,
+            layout,
+            natural_widths,
+            column_widths,
+        }
+    }
+}
+
+/// Iterator created by calling [`into_iter`](IntoIterator::into_iter) on [`LayoutTable`].
+///
+/// **Required features:** `std`
+#[derive(Debug, Clone)]
+pub struct LayoutTableIter<Value, PadBlock = char, Pad = Alignment, Separator = &'static str>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: for<'a> crate::Pad<&'a Value, PadBlock>
+        + crate::Pad<WrappedLine, PadBlock>
+        + Default
+        + Copy,
+    Separator: Width + Copy,
+{
+    row_list: LinkedList<Vec<Value>>,
+    pad_block: PadBlock,
+    layout: TableLayout<Pad, Separator>,
+    natural_widths: Vec<usize>,
+    column_widths: Vec<usize>,
+}
+
+impl<Value, PadBlock, Pad, Separator> LayoutTableIter<Value, PadBlock, Pad, Separator>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: for<'a> crate::Pad<&'a Value, PadBlock>
+        + crate::Pad<WrappedLine, PadBlock>
+        + Default
+        + Copy,
+    Separator: Width + Copy,
+{
+    /// Pad block that was used in the construction of [`LayoutTable`].
+    pub fn pad_block(&self) -> PadBlock {
+        self.pad_block
+    }
+
+    /// Layout settings that were used in the construction of [`LayoutTable`].
+    pub fn layout(&self) -> &'_ TableLayout<Pad, Separator> {
+        &self.layout
+    }
+
+    /// Maximum width of all values of each column.
+    pub fn natural_widths(&self) -> &'_ [usize] {
+        &self.natural_widths
+    }
+
+    /// Width of each column after shrinking (see [`TableLayout::solve`]).
+    pub fn column_widths(&self) -> &'_ [usize] {
+        &self.column_widths
+    }
+
+    /// Width of every line of a row that has values in every column.
+    pub fn total_width(&self) -> usize {
+        let separator_count = self.column_widths.len().saturating_sub(1);
+        self.column_widths.iter().sum::<usize>() + separator_count * self.layout.separator.width()
+    }
+}
+
+impl<Value, PadBlock, Pad, Separator> Iterator for LayoutTableIter<Value, PadBlock, Pad, Separator>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: for<'a> crate::Pad<&'a Value, PadBlock>
+        + crate::Pad<WrappedLine, PadBlock>
+        + Default
+        + Copy,
+    Separator: Width + Copy,
+{
+    type Item = LayoutRow<Value, PadBlock, Pad, Separator>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let LayoutTableIter {
+            row_list,
+            pad_block,
+            layout,
+            column_widths,
+            ..
+        } = self;
+        row_list.pop_front().map(|cells| LayoutRow {
+            columns: (0..cells.len())
+                .map(|index| (layout.column(index), column_widths[index]))
+                .collect(),
+            cells,
+            pad_block: *pad_block,
+            separator: layout.separator,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<Value, PadBlock, Pad, Separator> ExactSizeIterator
+    for LayoutTableIter<Value, PadBlock, Pad, Separator>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: for<'a> crate::Pad<&'a Value, PadBlock>
+        + crate::Pad<WrappedLine, PadBlock>
+        + Default
+        + Copy,
+    Separator: Width + Copy,
+{
+    fn len(&self) -> usize {
+        self.row_list.len()
+    }
+}
+
+/// A row of values laid out by [`LayoutTable`], yielded by [`LayoutTableIter`].
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`Display`]: Displays the lines of the row, separated by line feeds.
+///   Cells of columns with [`ShrinkPolicy::Wrap`] may span multiple lines,
+///   the other cells are followed by blank lines.
+#[derive(Debug, Clone)]
+pub struct LayoutRow<Value, PadBlock = char, Pad = Alignment, Separator = &'static str>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: for<'a> crate::Pad<&'a Value, PadBlock> + crate::Pad<WrappedLine, PadBlock> + Copy,
+    Separator: Width + Copy,
+{
+    cells: Vec<Value>,
+    columns: Vec<(ColumnLayout<Pad>, usize)>,
+    pad_block: PadBlock,
+    separator: Separator,
+}
+
+impl<Value, PadBlock, Pad, Separator> LayoutRow<Value, PadBlock, Pad, Separator>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: for<'a> crate::Pad<&'a Value, PadBlock> + crate::Pad<WrappedLine, PadBlock> + Copy,
+    Separator: Width + Copy,
+{
+    /// Values of the row.
+    pub fn cells(&self) -> &'_ [Value] {
+        &self.cells
+    }
+
+    /// Extract the values of the row.
+    pub fn into_cells(self) -> Vec<Value> {
+        self.cells
+    }
+
+    /// Separator between every two adjacent cells.
+    pub fn separator(&self) -> Separator {
+        self.separator
+    }
+
+    /// Wrap the cells of columns with [`ShrinkPolicy::Wrap`] onto lines of the width of their columns.
+    ///
+    /// The other cells have no lines. The lines are padded by the column later.
+    fn wrap(&self) -> Vec<Vec<WrappedLine>> {
+        self.cells
+            .iter()
+            .zip(self.columns.iter())
+            .map(|(cell, (column, width))| match column.shrink {
+                ShrinkPolicy::Wrap => WrappedValue {
+                    value: cell,
+                    pad_block: self.pad_block,
+                    total_width: *width,
+                    pad: AlignLeft,
+                }
+                .lines()
+                .map(|line| line.value)
+                .collect(),
+                ShrinkPolicy::Truncate | ShrinkPolicy::Ellipsize => Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Number of lines of the row whose wrapped cells are `lines`.
+    fn count_lines(lines: &[Vec<WrappedLine>]) -> usize {
+        lines.iter().map(Vec::len).max().unwrap_or(0).max(1)
+    }
+
+    /// Number of lines the row spans.
+    pub fn line_count(&self) -> usize {
+        Self::count_lines(&self.wrap())
+    }
+}
+
+impl<Value, PadBlock, Pad, Separator> Display for LayoutRow<Value, PadBlock, Pad, Separator>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: for<'a> crate::Pad<&'a Value, PadBlock> + crate::Pad<WrappedLine, PadBlock> + Copy,
+    Separator: Width + Copy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        let lines = self.wrap();
+        for line_index in 0..Self::count_lines(&lines) {
+            if line_index != 0 {
+                writeln!(formatter)?;
+            }
+            for (index, (cell, (column, width))) in
+                self.cells.iter().zip(self.columns.iter()).enumerate()
+            {
+                if index != 0 {
+                    write!(formatter, "{}", self.separator)?;
+                }
+                let handle_excess = column.shrink.excess_policy();
+                match column.shrink {
+                    ShrinkPolicy::Wrap => match lines[index].get(line_index) {
+                        Some(line) => {
+                            let padded_value = PaddedValue {
+                                value: line.clone(),
+                                pad_block: self.pad_block,
+                                total_width: *width,
+                                pad: column.pad,
+                                handle_excess,
+                            };
+                            write!(formatter, "{}", padded_value)?;
+                        }
+                        None => self.pad_block.fill(formatter, *width)?,
+                    },
+                    ShrinkPolicy::Truncate | ShrinkPolicy::Ellipsize if line_index == 0 => {
+                        let padded_value = PaddedValue {
+                            value: cell,
+                            pad_block: self.pad_block,
+                            total_width: *width,
+                            pad: column.pad,
+                            handle_excess,
+                        };
+                        write!(formatter, "{}", padded_value)?;
+                    }
+                    ShrinkPolicy::Truncate | ShrinkPolicy::Ellipsize => {
+                        self.pad_block.fill(formatter, *width)?
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
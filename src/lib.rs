@@ -4,6 +4,10 @@
 //! * `std` _(default feature)_:
 //!   - Disable `#![no_std]`.
 //!   - Enable features that require heap allocation.
+//! * `grapheme`:
+//!   - Enable grapheme-cluster-aware width measurement ([`GraphemeWidth`]).
+//! * `serde`:
+//!   - Implement `Serialize` and `Deserialize` for [`Alignment`].
 //!
 //! **Usage:**
 //!
@@ -11,32 +15,99 @@
 //! [`align_left`], [`align_right`], [`align_center_left`], [`align_center_right`],
 //! [`align_column_left`], [`align_column_right`],
 //! [`align_column_center_left`], [`align_column_center_right`],
-//! [`PaddedValue`], [`PaddedColumn`], [`Alignment`],
-//! [`AlignLeft`], [`AlignRight`], [`AlignCenterLeft`], [`AlignCenterRight`],
+//! [`PaddedValue`], [`PaddedColumn`], [`ConstrainedColumn`], [`WidthConstraint`],
+//! [`TwoPassColumn`], [`StreamingColumn`], [`LabeledColumn`], [`BufferedColumnIter`],
+//! [`PaddedTable`], [`LayoutTable`], [`BorderedTable`], [`TableStyle`],
+//! [`Alignment`], [`FormatSpec`], [`pad_args!`], [`pad_write!`],
+//! [`AlignLeft`], [`AlignRight`], [`AlignCenterLeft`], [`AlignCenterRight`], [`AlignDecimal`],
+//! [`AlignRatio`], [`Precision`], [`MeasuredDisplay`], [`OptionalValue`],
+//! [`JustifyBetween`], [`JustifyBiasLeft`], [`JustifyBiasRight`], [`Margin`],
 //! etc.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod alignment;
+mod ansi;
+mod arguments;
+mod border;
+mod buffered;
+mod checked;
+mod constraint;
+mod decimal;
+mod error;
 mod excess;
+mod justify;
+mod macros;
+mod margin;
+mod measured;
+mod numeric;
+mod optional;
 mod pad;
+mod pad_block;
+mod ratio;
 mod shortcuts;
+mod spec;
+mod truncate;
+mod two_pass;
 mod unit;
 mod value;
 mod width;
 
 pub use alignment::*;
+pub use arguments::*;
+pub use border::*;
+pub use buffered::*;
+pub use checked::*;
+pub use constraint::*;
+pub use decimal::*;
+pub use error::*;
 pub use excess::*;
+pub use justify::*;
+pub use margin::*;
+pub use measured::*;
+pub use numeric::*;
+pub use optional::*;
 pub use pad::*;
+pub use pad_block::*;
+pub use ratio::*;
 pub use shortcuts::*;
+pub use spec::*;
+pub use two_pass::*;
 pub use unit::*;
 pub use value::*;
 pub use width::*;
 
+#[cfg(feature = "grapheme")]
+mod grapheme;
+
+#[cfg(feature = "std")]
+mod bordered;
 #[cfg(feature = "std")]
 mod column;
+#[cfg(feature = "std")]
+mod labeled;
+#[cfg(feature = "std")]
+mod layout;
+#[cfg(feature = "std")]
+mod streaming;
+#[cfg(feature = "std")]
+mod table;
+#[cfg(feature = "std")]
+mod wrap;
 
+#[cfg(feature = "std")]
+pub use bordered::*;
 #[cfg(feature = "std")]
 pub use column::*;
+#[cfg(feature = "std")]
+pub use labeled::*;
+#[cfg(feature = "std")]
+pub use layout::*;
+#[cfg(feature = "std")]
+pub use streaming::*;
+#[cfg(feature = "std")]
+pub use table::*;
+#[cfg(feature = "std")]
+pub use wrap::*;
 
 pub use fmt_iter;
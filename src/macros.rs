@@ -0,0 +1,133 @@
+/// Create [`PadArguments`](crate::PadArguments) from a template and a list of arguments.
+///
+/// Arguments are either positional (`value`) or named (`name = value`),
+/// every value must implement [`Width`](crate::Width).
+///
+/// **Syntax of the template:**
+/// * `{{` and `}}` are literal braces.
+/// * `{}` is the next positional argument, `{0}` is the argument at index 0,
+///   `{name}` is the argument named `name`.
+/// * A placeholder may be followed by a colon and a [`FormatSpec`](crate::FormatSpec), such as `{name:<20}`.
+///
+/// The template must be a string literal, it is parsed when the macro is compiled.
+/// Unmatched braces, unknown or unused arguments and invalid format specs stop the compilation
+/// with an "index out of bounds" error whose variable names the mistake
+/// (such as `unknown_argument_in_template`) and whose index is the byte offset of the mistake
+/// in the template (or the index of the unused argument).
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::pad_args;
+/// let line = pad_args!("|{:^6}|{price:>7.}|", "\u{4F60}\u{597D}", price = "12.5");
+/// assert_eq!(line.to_string(), "| \u{4F60}\u{597D} |   12.5|");
+/// ```
+///
+/// **Example:** Unknown argument
+///
+/// ```compile_fail
+/// use zero_copy_pads::pad_args;
+/// let line = pad_args!("{b:>5}", a = "x");
+/// ```
+///
+/// **Example:** Unused argument
+///
+/// ```compile_fail
+/// use zero_copy_pads::pad_args;
+/// let line = pad_args!("{:>5}", "x", "y");
+/// ```
+///
+/// **Example:** Invalid format spec
+///
+/// ```compile_fail
+/// use zero_copy_pads::pad_args;
+/// let line = pad_args!("{:<}", "x");
+/// ```
+#[macro_export]
+macro_rules! pad_args {
+    ($template:literal $(,)?) => {
+        $crate::pad_args!(@munch $template; [];)
+    };
+
+    ($template:literal, $($arguments:tt)+) => {
+        $crate::pad_args!(@munch $template; []; $($arguments)+)
+    };
+
+    (@munch $template:literal; [$($done:tt)*]; $name:ident = $value:expr, $($rest:tt)*) => {
+        $crate::pad_args!(@munch $template; [$($done)* (::core::option::Option::Some(stringify!($name)), &$value)]; $($rest)*)
+    };
+
+    (@munch $template:literal; [$($done:tt)*]; $name:ident = $value:expr) => {
+        $crate::pad_args!(@munch $template; [$($done)* (::core::option::Option::Some(stringify!($name)), &$value)];)
+    };
+
+    (@munch $template:literal; [$($done:tt)*]; $value:expr, $($rest:tt)*) => {
+        $crate::pad_args!(@munch $template; [$($done)* (::core::option::Option::None, &$value)]; $($rest)*)
+    };
+
+    (@munch $template:literal; [$($done:tt)*]; $value:expr) => {
+        $crate::pad_args!(@munch $template; [$($done)* (::core::option::Option::None, &$value)];)
+    };
+
+    (@munch $template:literal; [$(($name:expr, $value:expr))*];) => {
+        $crate::PadArguments {
+            template: $template,
+            segments: {
+                const NAMES: &[::core::option::Option<&str>] = &[$($name),*];
+                const LEN: usize = $crate::count_template_segments($template, NAMES);
+                const SEGMENTS: [$crate::TemplateSegment; LEN] =
+                    $crate::parse_template($template, NAMES);
+                &SEGMENTS
+            },
+            arguments: &[$($value),*],
+        }
+    };
+}
+
+/// Write a template with padded arguments into a writer.
+///
+/// The writer is either a [`core::fmt::Write`] or a [`std::io::Write`],
+/// the arguments are the same as [`pad_args!`](crate::pad_args).
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::pad_write;
+/// use core::fmt::Write;
+/// let mut output = String::new();
+/// pad_write!(output, "{name:<8}|{age:>4}", name = "\u{4F60}\u{597D}", age = "30").unwrap();
+/// assert_eq!(output, "\u{4F60}\u{597D}    |  30");
+/// ```
+#[macro_export]
+macro_rules! pad_write {
+    ($writer:expr, $($arguments:tt)+) => {
+        write!($writer, "{}", $crate::pad_args!($($arguments)+))
+    };
+}
+
+/// Create a [`String`] from a template with padded arguments.
+///
+/// The arguments are the same as [`pad_args!`](crate::pad_args).
+///
+/// **Required features:** `std`
+///
+/// **Example:**
+///
+/// ```
+/// # #[cfg(feature = "std")] fn main() {
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::pad_format;
+/// let line = pad_format!("[{:^-9}]", "\u{1F600}x");
+/// assert_eq!(line, "[---\u{1F600}x---]");
+/// # }
+/// # #[cfg(not(feature = "std"))] fn main() {}
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! pad_format {
+    ($($arguments:tt)+) => {
+        $crate::pad_args!($($arguments)+).to_string()
+    };
+}
@@ -0,0 +1,131 @@
+use crate::{Alignment, Pad, Width};
+use core::fmt::{Error, Formatter};
+
+/// Reserve a fixed number of pad columns on each side, pad the value in the remaining width.
+///
+/// When the pad is too narrow for both margins, the left margin is kept first,
+/// then the right margin, and the value is not padded any further.
+///
+/// **Example:** A gutter of 1 column on each side then align right
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{AlignRight, Margin, PaddedValue, PanicOnExcess};
+/// let padded_value = PaddedValue {
+///     pad: Margin {
+///         left: 1,
+///         right: 1,
+///         inner: AlignRight,
+///     },
+///     value: "abc",
+///     pad_block: '.',
+///     total_width: 8,
+///     handle_excess: PanicOnExcess,
+/// };
+/// assert_eq!(padded_value.to_string(), "....abc.");
+/// ```
+///
+/// **Example:** Distinct pad blocks on each side
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{AlignCenterLeft, Margin, PaddedValue, PanicOnExcess, SidedPadBlock};
+/// let padded_value = PaddedValue {
+///     pad: Margin {
+///         left: 2,
+///         right: 0,
+///         inner: AlignCenterLeft,
+///     },
+///     value: "abc",
+///     pad_block: SidedPadBlock {
+///         left: '>',
+///         right: '<',
+///     },
+///     total_width: 10,
+///     handle_excess: PanicOnExcess,
+/// };
+/// assert_eq!(padded_value.to_string(), ">>>>abc<<<");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Margin<Inner = Alignment> {
+    /// Number of columns to the left of the padded value.
+    pub left: usize,
+    /// Number of columns to the right of the padded value.
+    pub right: usize,
+    /// How to pad the value within the remaining width.
+    pub inner: Inner,
+}
+
+impl<Value, PadBlock, Inner> Pad<Value, PadBlock> for Margin<Inner>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock,
+    Inner: Pad<Value, PadBlock>,
+{
+    fn fmt(
+        &self,
+        formatter: &mut Formatter<'_>,
+        value: &Value,
+        pad_block: &PadBlock,
+        pad_width: usize,
+    ) -> Result<(), Error> {
+        let left = self.left.min(pad_width);
+        let right = self.right.min(pad_width - left);
+        pad_block.fill_left(formatter, left)?;
+        self.inner
+            .fmt(formatter, value, pad_block, pad_width - left - right)?;
+        pad_block.fill_right(formatter, right)
+    }
+}
+
+/// Pad block that uses different blocks to the left and to the right of a value.
+///
+/// Pads that are neither to the left nor to the right of a value
+/// (such as the gaps of [`JustifyBetween`](crate::JustifyBetween)) use the `left` block.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{AlignCenterRight, PaddedValue, PanicOnExcess, SidedPadBlock};
+/// let padded_value = PaddedValue {
+///     pad: AlignCenterRight,
+///     value: "abc",
+///     pad_block: SidedPadBlock {
+///         left: "=>",
+///         right: "<=",
+///     },
+///     total_width: 11,
+///     handle_excess: PanicOnExcess,
+/// };
+/// assert_eq!(padded_value.to_string(), "=>=>abc<=<=");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SidedPadBlock<Left, Right>
+where
+    Left: crate::PadBlock,
+    Right: crate::PadBlock,
+{
+    /// Block to the left of the value.
+    pub left: Left,
+    /// Block to the right of the value.
+    pub right: Right,
+}
+
+impl<Left, Right> crate::PadBlock for SidedPadBlock<Left, Right>
+where
+    Left: crate::PadBlock,
+    Right: crate::PadBlock,
+{
+    fn fill(&self, formatter: &mut Formatter<'_>, width: usize) -> Result<(), Error> {
+        self.left.fill(formatter, width)
+    }
+
+    fn fill_left(&self, formatter: &mut Formatter<'_>, width: usize) -> Result<(), Error> {
+        self.left.fill_left(formatter, width)
+    }
+
+    fn fill_right(&self, formatter: &mut Formatter<'_>, width: usize) -> Result<(), Error> {
+        self.right.fill_right(formatter, width)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::Width;
+use core::fmt::{Arguments, Display, Error, Formatter, Write};
+use derive_more::{AsMut, AsRef, Deref, DerefMut, From};
+use unicode_width::UnicodeWidthStr;
+
+/// [`Write`] that only sums the widths of the text written to it.
+struct WidthCounter(usize);
+
+impl Write for WidthCounter {
+    fn write_str(&mut self, text: &str) -> Result<(), Error> {
+        self.0 += UnicodeWidthStr::width(text);
+        Ok(())
+    }
+}
+
+/// Measure the [`Display`] output of a value without allocation.
+///
+/// Unlike `truncate::display_width`, ANSI escape sequences are not skipped.
+pub(crate) fn raw_display_width(value: impl Display) -> usize {
+    let mut counter = WidthCounter(0);
+    write!(counter, "{}", value).expect("writing to a counter never fails");
+    counter.0
+}
+
+/// Treat [`UnicodeWidthStr::width`] of the [`Display`] output as width.
+///
+/// The output is streamed through a counter instead of being collected into a string,
+/// so any [`Display`] type can be padded without heap allocation.
+/// The value is formatted twice: once to measure it, once to display it.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{align_right, MeasuredDisplay, Width};
+/// use core::fmt::{Display, Error, Formatter};
+/// struct Point(i32, i32);
+/// impl Display for Point {
+///     fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+///         write!(formatter, "({}, {})", self.0, self.1)
+///     }
+/// }
+/// let value = MeasuredDisplay::from(Point(3, -4));
+/// assert_eq!(value.width(), 7);
+/// assert_eq!(align_right(value, 10).to_string(), "   (3, -4)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AsMut, AsRef, Deref, DerefMut, From)]
+pub struct MeasuredDisplay<Inner: Display>(Inner);
+
+impl<Inner: Display> MeasuredDisplay<Inner> {
+    /// Extract the inner value.
+    pub fn into_inner(self) -> Inner {
+        self.0
+    }
+
+    /// Get reference to inner value.
+    pub fn as_inner(&self) -> &'_ Inner {
+        &self.0
+    }
+}
+
+impl<Inner: Display> Display for MeasuredDisplay<Inner> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(formatter, "{}", self.0)
+    }
+}
+
+impl<Inner: Display> Width for MeasuredDisplay<Inner> {
+    fn width(&self) -> usize {
+        raw_display_width(&self.0)
+    }
+}
+
+impl<'a> Width for Arguments<'a> {
+    fn width(&self) -> usize {
+        raw_display_width(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> Width for std::path::Display<'a> {
+    fn width(&self) -> usize {
+        raw_display_width(self)
+    }
+}
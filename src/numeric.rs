@@ -0,0 +1,102 @@
+use crate::{measured::raw_display_width, UnicodeWidthChar, Width};
+use core::fmt::{Display, Error, Formatter};
+
+/// Count the number of decimal digits of an unsigned integer.
+macro_rules! count_digits {
+    ($value:expr) => {{
+        let mut value = $value;
+        let mut digits = 1;
+        while value >= 10 {
+            value /= 10;
+            digits += 1;
+        }
+        digits
+    }};
+}
+
+macro_rules! unsigned_width {
+    ($($type:ty),* $(,)?) => {$(
+        impl Width for $type {
+            fn width(&self) -> usize {
+                count_digits!(*self)
+            }
+        }
+    )*};
+}
+
+macro_rules! signed_width {
+    ($($type:ty),* $(,)?) => {$(
+        impl Width for $type {
+            fn width(&self) -> usize {
+                (*self < 0) as usize + count_digits!(self.unsigned_abs())
+            }
+        }
+    )*};
+}
+
+unsigned_width!(u8, u16, u32, u64, u128, usize);
+signed_width!(i8, i16, i32, i64, i128, isize);
+
+impl Width for char {
+    fn width(&self) -> usize {
+        UnicodeWidthChar::width(*self).unwrap_or(0)
+    }
+}
+
+impl Width for bool {
+    fn width(&self) -> usize {
+        if *self {
+            "true".len()
+        } else {
+            "false".len()
+        }
+    }
+}
+
+impl Width for f32 {
+    fn width(&self) -> usize {
+        raw_display_width(self)
+    }
+}
+
+impl Width for f64 {
+    fn width(&self) -> usize {
+        raw_display_width(self)
+    }
+}
+
+/// Display a floating-point number with a fixed number of digits after the decimal point.
+///
+/// The width is that of the rounded output, so padding uses the same digits that are displayed.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{align_right, Precision, Width};
+/// let value = Precision {
+///     value: 1.23456,
+///     precision: 2,
+/// };
+/// assert_eq!(value.width(), 4);
+/// assert_eq!(align_right(value, 8).to_string(), "    1.23");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Precision<Float> {
+    /// Number to display.
+    pub value: Float,
+    /// Number of digits after the decimal point.
+    pub precision: usize,
+}
+
+impl<Float: Display> Display for Precision<Float> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(formatter, "{:.*}", self.precision, self.value)
+    }
+}
+
+impl<Float: Display> Width for Precision<Float> {
+    fn width(&self) -> usize {
+        raw_display_width(self)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{CharWidthCallback, Width};
+use core::fmt::{Display, Error, Formatter};
+
+/// Display an optional value, or a placeholder when there is no value.
+///
+/// [`Option`] implements neither [`Display`] nor [`Width`], wrap it to pad it.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{align_right, OptionalValue, Width};
+/// let present = OptionalValue {
+///     value: Some(1234),
+///     placeholder: "-",
+/// };
+/// let absent = OptionalValue {
+///     value: None::<i32>,
+///     placeholder: "-",
+/// };
+/// assert_eq!(present.width(), 4);
+/// assert_eq!(absent.width(), 1);
+/// assert_eq!(align_right(present, 6).to_string(), "  1234");
+/// assert_eq!(align_right(absent, 6).to_string(), "     -");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OptionalValue<Value, Placeholder = &'static str> {
+    /// Value to display if any.
+    pub value: Option<Value>,
+    /// What to display when `value` is [`None`].
+    pub placeholder: Placeholder,
+}
+
+impl<Value: Display, Placeholder: Display> Display for OptionalValue<Value, Placeholder> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        match &self.value {
+            Some(value) => write!(formatter, "{}", value),
+            None => write!(formatter, "{}", self.placeholder),
+        }
+    }
+}
+
+impl<Value: Width, Placeholder: Width> Width for OptionalValue<Value, Placeholder> {
+    fn width(&self) -> usize {
+        match &self.value {
+            Some(value) => value.width(),
+            None => self.placeholder.width(),
+        }
+    }
+
+    fn for_each_char_width(&self, callback: CharWidthCallback<'_>) -> Result<(), Error> {
+        match &self.value {
+            Some(value) => value.for_each_char_width(callback),
+            None => self.placeholder.for_each_char_width(callback),
+        }
+    }
+}
@@ -1,13 +1,12 @@
 use crate::{Unit, Width};
-use core::fmt::{Display, Error, Formatter};
-use fmt_iter::repeat;
+use core::fmt::{Error, Formatter};
 
 /// Pad a value knowing the number of blocks.
 ///
 /// Values that implement this trait are to be passed
 /// to `pad` field of [`PaddedValue`](crate::PaddedValue)
 /// or [`PaddedColumn`](crate::PaddedColumn).
-pub trait Pad<Value: Width, PadBlock: Display> {
+pub trait Pad<Value: Width, PadBlock: crate::PadBlock> {
     /// Pad a value knowing the number of blocks.
     fn fmt(
         &self,
@@ -21,7 +20,7 @@ pub trait Pad<Value: Width, PadBlock: Display> {
 impl<Value, PadBlock, X> Pad<Value, PadBlock> for &X
 where
     Value: Width,
-    PadBlock: Display,
+    PadBlock: crate::PadBlock,
     X: Pad<Value, PadBlock> + Sized,
 {
     fn fmt(
@@ -36,7 +35,7 @@ where
 }
 
 /// All pre-defined zero-sized [`Pad`] types in this [crate] implement this trait.
-pub trait UnitPad<Value: Width, PadBlock: Display>: Unit + Pad<Value, PadBlock> {}
+pub trait UnitPad<Value: Width, PadBlock: crate::PadBlock>: Unit + Pad<Value, PadBlock> {}
 
 macro_rules! unit_pad {
     ($name:ident) => {
@@ -44,7 +43,7 @@ macro_rules! unit_pad {
             const VALUE: Self = $name;
         }
 
-        impl<Value: Width, PadBlock: Display> UnitPad<Value, PadBlock> for $name {}
+        impl<Value: Width, PadBlock: crate::PadBlock> UnitPad<Value, PadBlock> for $name {}
     };
 }
 
@@ -68,7 +67,7 @@ macro_rules! unit_pad {
 pub struct AlignLeft;
 unit_pad!(AlignLeft);
 
-impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for AlignLeft {
+impl<Value: Width, PadBlock: crate::PadBlock> Pad<Value, PadBlock> for AlignLeft {
     fn fmt(
         &self,
         formatter: &mut Formatter<'_>,
@@ -76,8 +75,8 @@ impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for AlignLeft {
         pad_block: &PadBlock,
         pad_width: usize,
     ) -> Result<(), Error> {
-        let pad = repeat(pad_block, pad_width);
-        write!(formatter, "{}{}", value, pad)
+        write!(formatter, "{}", value)?;
+        pad_block.fill_right(formatter, pad_width)
     }
 }
 
@@ -101,7 +100,7 @@ impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for AlignLeft {
 pub struct AlignRight;
 unit_pad!(AlignRight);
 
-impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for AlignRight {
+impl<Value: Width, PadBlock: crate::PadBlock> Pad<Value, PadBlock> for AlignRight {
     fn fmt(
         &self,
         formatter: &mut Formatter<'_>,
@@ -109,8 +108,8 @@ impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for AlignRight {
         pad_block: &PadBlock,
         pad_width: usize,
     ) -> Result<(), Error> {
-        let pad = repeat(pad_block, pad_width);
-        write!(formatter, "{}{}", pad, value)
+        pad_block.fill_left(formatter, pad_width)?;
+        write!(formatter, "{}", value)
     }
 }
 
@@ -149,7 +148,7 @@ impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for AlignRight {
 pub struct AlignCenterLeft;
 unit_pad!(AlignCenterLeft);
 
-impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for AlignCenterLeft {
+impl<Value: Width, PadBlock: crate::PadBlock> Pad<Value, PadBlock> for AlignCenterLeft {
     fn fmt(
         &self,
         formatter: &mut Formatter<'_>,
@@ -157,9 +156,11 @@ impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for AlignCenterLeft {
         pad_block: &PadBlock,
         pad_width: usize,
     ) -> Result<(), Error> {
-        let pad = repeat(pad_block, pad_width >> 1);
-        let remainder = repeat(pad_block, pad_width & 1);
-        write!(formatter, "{}{}{}{}", pad, value, pad, remainder)
+        let pad = pad_width >> 1;
+        let remainder = pad_width & 1;
+        pad_block.fill_left(formatter, pad)?;
+        write!(formatter, "{}", value)?;
+        pad_block.fill_right(formatter, pad + remainder)
     }
 }
 
@@ -198,7 +199,7 @@ impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for AlignCenterLeft {
 pub struct AlignCenterRight;
 unit_pad!(AlignCenterRight);
 
-impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for AlignCenterRight {
+impl<Value: Width, PadBlock: crate::PadBlock> Pad<Value, PadBlock> for AlignCenterRight {
     fn fmt(
         &self,
         formatter: &mut Formatter<'_>,
@@ -206,8 +207,10 @@ impl<Value: Width, PadBlock: Display> Pad<Value, PadBlock> for AlignCenterRight
         pad_block: &PadBlock,
         pad_width: usize,
     ) -> Result<(), Error> {
-        let pad = repeat(pad_block, pad_width >> 1);
-        let remainder = repeat(pad_block, pad_width & 1);
-        write!(formatter, "{}{}{}{}", pad, remainder, value, pad)
+        let pad = pad_width >> 1;
+        let remainder = pad_width & 1;
+        pad_block.fill_left(formatter, pad + remainder)?;
+        write!(formatter, "{}", value)?;
+        pad_block.fill_right(formatter, pad)
     }
 }
@@ -0,0 +1,111 @@
+use crate::truncate::display_width;
+use core::fmt::{Display, Error, Formatter};
+use fmt_iter::repeat;
+
+/// Block that fills the pad of a value.
+///
+/// The width of a block is measured from its displayed characters,
+/// so multi-character blocks (such as `". "`) and wide characters (such as `'\u{3000}'`)
+/// are repeated as many whole times as they fit, and the remaining columns are filled
+/// with a fallback block.
+///
+/// Every [`Display`] type is a pad block whose fallback is a space character.
+/// Use [`PadBlockWithFallback`] to configure the fallback.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{AlignLeft, PaddedValue, PanicOnExcess};
+/// let padded_value = PaddedValue {
+///     pad: AlignLeft,
+///     value: "abc",
+///     pad_block: ". ",
+///     total_width: 10,
+///     handle_excess: PanicOnExcess,
+/// };
+/// assert_eq!(padded_value.to_string(), "abc. . .  ");
+/// ```
+pub trait PadBlock {
+    /// Write blocks that fill exactly `width` columns.
+    fn fill(&self, formatter: &mut Formatter<'_>, width: usize) -> Result<(), Error>;
+
+    /// Write blocks that fill exactly `width` columns to the left of a value.
+    fn fill_left(&self, formatter: &mut Formatter<'_>, width: usize) -> Result<(), Error> {
+        self.fill(formatter, width)
+    }
+
+    /// Write blocks that fill exactly `width` columns to the right of a value.
+    fn fill_right(&self, formatter: &mut Formatter<'_>, width: usize) -> Result<(), Error> {
+        self.fill(formatter, width)
+    }
+}
+
+impl<Block: Display + ?Sized> PadBlock for Block {
+    fn fill(&self, formatter: &mut Formatter<'_>, width: usize) -> Result<(), Error> {
+        fill_with_fallback(formatter, self, &' ', width)
+    }
+}
+
+/// Pad block whose remaining columns are filled with a custom fallback block.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{AlignRight, PadBlockWithFallback, PaddedValue, PanicOnExcess};
+/// let padded_value = PaddedValue {
+///     pad: AlignRight,
+///     value: "abc",
+///     pad_block: PadBlockWithFallback {
+///         block: '\u{3000}',
+///         fallback: '.',
+///     },
+///     total_width: 8,
+///     handle_excess: PanicOnExcess,
+/// };
+/// assert_eq!(padded_value.to_string(), "\u{3000}\u{3000}.abc");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PadBlockWithFallback<Block, Fallback = char>
+where
+    Block: Display,
+    Fallback: PadBlock,
+{
+    /// Block to be repeated.
+    pub block: Block,
+    /// Block that fills the columns that are too few for another `block`.
+    pub fallback: Fallback,
+}
+
+impl<Block, Fallback> PadBlock for PadBlockWithFallback<Block, Fallback>
+where
+    Block: Display,
+    Fallback: PadBlock,
+{
+    fn fill(&self, formatter: &mut Formatter<'_>, width: usize) -> Result<(), Error> {
+        fill_with_fallback(formatter, &self.block, &self.fallback, width)
+    }
+}
+
+/// Repeat `block` as many whole times as it fits in `width`, fill the rest with `fallback`.
+fn fill_with_fallback<Block, Fallback>(
+    formatter: &mut Formatter<'_>,
+    block: &Block,
+    fallback: &Fallback,
+    width: usize,
+) -> Result<(), Error>
+where
+    Block: Display + ?Sized,
+    Fallback: PadBlock + ?Sized,
+{
+    let block_width = display_width(block);
+    if block_width == 1 {
+        return write!(formatter, "{}", repeat(block, width));
+    }
+    if block_width == 0 {
+        return fallback.fill(formatter, width);
+    }
+    write!(formatter, "{}", repeat(block, width / block_width))?;
+    fallback.fill(formatter, width % block_width)
+}
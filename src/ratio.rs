@@ -0,0 +1,106 @@
+use crate::{Pad, Width};
+use core::fmt::{Error, Formatter};
+
+/// How to round the number of pad blocks of [`AlignRatio`] when it is not a whole number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round toward zero, shifting the value to the left.
+    Down,
+    /// Round away from zero, shifting the value to the right.
+    Up,
+    /// Round to the nearest whole number, round halves down.
+    HalfDown,
+    /// Round to the nearest whole number, round halves up.
+    HalfUp,
+}
+
+impl Default for Rounding {
+    /// Round toward zero, shifting the value to the left.
+    fn default() -> Self {
+        Rounding::Down
+    }
+}
+
+impl Rounding {
+    /// Compute `dividend / divisor` rounded according to the policy.
+    ///
+    /// Return 0 when `divisor` is 0.
+    pub fn divide(self, dividend: usize, divisor: usize) -> usize {
+        self.divide_wide(dividend as u128, divisor as u128) as usize
+    }
+
+    /// Same as [`divide`](Rounding::divide) but wide enough for the product of two [`usize`].
+    fn divide_wide(self, dividend: u128, divisor: u128) -> u128 {
+        if divisor == 0 {
+            return 0;
+        }
+        let quotient = dividend / divisor;
+        let remainder = dividend % divisor;
+        let round_up = match self {
+            Rounding::Down => false,
+            Rounding::Up => remainder != 0,
+            Rounding::HalfDown => remainder > divisor - remainder,
+            Rounding::HalfUp => remainder >= divisor - remainder && remainder != 0,
+        };
+        quotient + round_up as u128
+    }
+}
+
+/// Place the value at a fractional position within the pad.
+///
+/// `numerator / denominator` of the pad goes to the left of the value,
+/// the rest goes to the right. Ratios greater than 1 are treated as 1,
+/// a denominator of 0 is treated as a ratio of 0.
+///
+/// [`AlignCenterLeft`](crate::AlignCenterLeft) is equivalent to a ratio of `1 / 2` with [`Rounding::Down`],
+/// and [`AlignCenterRight`](crate::AlignCenterRight) is equivalent to a ratio of `1 / 2` with [`Rounding::Up`].
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{AlignRatio, PaddedValue, PanicOnExcess, Rounding};
+/// let padded_value = PaddedValue {
+///     pad: AlignRatio {
+///         numerator: 1,
+///         denominator: 3,
+///         rounding: Rounding::HalfUp,
+///     },
+///     value: "abc",
+///     pad_block: '-',
+///     total_width: 11,
+///     handle_excess: PanicOnExcess,
+/// };
+/// assert_eq!(padded_value.to_string(), "---abc-----");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AlignRatio {
+    /// Numerator of the fraction of the pad to the left of the value.
+    pub numerator: usize,
+    /// Denominator of the fraction of the pad to the left of the value.
+    pub denominator: usize,
+    /// How to round the number of pad blocks to the left of the value.
+    pub rounding: Rounding,
+}
+
+impl<Value: Width, PadBlock: crate::PadBlock> Pad<Value, PadBlock> for AlignRatio {
+    fn fmt(
+        &self,
+        formatter: &mut Formatter<'_>,
+        value: &Value,
+        pad_block: &PadBlock,
+        pad_width: usize,
+    ) -> Result<(), Error> {
+        let numerator = self.numerator.min(self.denominator);
+        let left = self
+            .rounding
+            .divide_wide(
+                pad_width as u128 * numerator as u128,
+                self.denominator as u128,
+            )
+            .min(pad_width as u128) as usize;
+        pad_block.fill_left(formatter, left)?;
+        write!(formatter, "{}", value)?;
+        pad_block.fill_right(formatter, pad_width - left)
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{
+    Alignment, Ellipsize, ErrorOnExcess, Excess, ExcessHandler, IgnoreExcess, PaddedValue,
+    PanicOnExcess, ParseFormatSpecError, TruncateEnd, TruncateMiddle, TruncateStart, Width,
+};
+use core::{
+    fmt::{Display, Error, Formatter},
+    str::FromStr,
+};
+
+/// Excess handler that can be chosen at runtime, see [`FormatSpec`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExcessPolicy {
+    /// Behave like [`IgnoreExcess`] (no suffix).
+    Ignore,
+    /// Behave like [`PanicOnExcess`] (suffix `!`).
+    Panic,
+    /// Behave like [`ErrorOnExcess`] (suffix `?`).
+    Error,
+    /// Behave like [`TruncateStart`] (suffix `<`).
+    TruncateStart,
+    /// Behave like [`TruncateEnd`] (suffix `>`).
+    TruncateEnd,
+    /// Behave like [`TruncateMiddle`] (suffix `^`).
+    TruncateMiddle,
+    /// Behave like the [default](Ellipsize::default) [`Ellipsize`] (suffix `.`).
+    Ellipsize,
+}
+
+impl Default for ExcessPolicy {
+    /// Behave like [`IgnoreExcess`] (no suffix).
+    fn default() -> Self {
+        ExcessPolicy::Ignore
+    }
+}
+
+impl ExcessPolicy {
+    /// Character that represents the policy in a [`FormatSpec`].
+    pub fn symbol(self) -> Option<char> {
+        Some(match self {
+            ExcessPolicy::Ignore => return None,
+            ExcessPolicy::Panic => '!',
+            ExcessPolicy::Error => '?',
+            ExcessPolicy::TruncateStart => '<',
+            ExcessPolicy::TruncateEnd => '>',
+            ExcessPolicy::TruncateMiddle => '^',
+            ExcessPolicy::Ellipsize => '.',
+        })
+    }
+
+    /// Find the policy that is represented by a character.
+    pub const fn from_symbol(symbol: char) -> Option<Self> {
+        Some(match symbol {
+            '!' => ExcessPolicy::Panic,
+            '?' => ExcessPolicy::Error,
+            '<' => ExcessPolicy::TruncateStart,
+            '>' => ExcessPolicy::TruncateEnd,
+            '^' => ExcessPolicy::TruncateMiddle,
+            '.' => ExcessPolicy::Ellipsize,
+            _ => return None,
+        })
+    }
+}
+
+impl<Value, PadBlock> ExcessHandler<Value, PadBlock> for ExcessPolicy
+where
+    Value: Width,
+    PadBlock: crate::PadBlock,
+{
+    fn handle_excess(
+        &self,
+        excess: Excess<Value, PadBlock>,
+        formatter: &mut Formatter<'_>,
+    ) -> Result<(), Error> {
+        match self {
+            ExcessPolicy::Ignore => IgnoreExcess.handle_excess(excess, formatter),
+            ExcessPolicy::Panic => PanicOnExcess.handle_excess(excess, formatter),
+            ExcessPolicy::Error => ErrorOnExcess.handle_excess(excess, formatter),
+            ExcessPolicy::TruncateStart => TruncateStart.handle_excess(excess, formatter),
+            ExcessPolicy::TruncateEnd => TruncateEnd.handle_excess(excess, formatter),
+            ExcessPolicy::TruncateMiddle => TruncateMiddle.handle_excess(excess, formatter),
+            ExcessPolicy::Ellipsize => Ellipsize::default().handle_excess(excess, formatter),
+        }
+    }
+}
+
+/// Padding configuration parsed from a compact format specification.
+///
+/// **Syntax:** `[align[fill]]width[excess]`
+/// * `align` _(defaults to [`Alignment::Left`])_:
+///   - `<`: [`Alignment::Left`].
+///   - `>`: [`Alignment::Right`].
+///   - `^`: [`Alignment::CenterLeft`].
+///   - `~`: [`Alignment::CenterRight`].
+///   - `=`: [`Alignment::JustifyBiasLeft`].
+///   - `#`: [`Alignment::JustifyBiasRight`].
+/// * `fill` _(defaults to a space)_: Any character, it is the pad block.
+///   It can only be given after `align`, and can be escaped with a backslash (e.g. `\\` for a backslash).
+///   An ASCII digit must be escaped (e.g. `>\08` pads with `0`), an unescaped digit there is always part of `width`.
+/// * `width`: Total width in decimal digits.
+/// * `excess` _(defaults to [`ExcessPolicy::Ignore`])_: Symbol of an [`ExcessPolicy`].
+///
+/// Unlike the width in the format strings of [`core::fmt`],
+/// the width is measured by [`Width`], so wide characters are counted correctly.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{Alignment, ExcessPolicy, FormatSpec};
+/// let spec: FormatSpec = "^-12.".parse().unwrap();
+/// assert_eq!(spec, FormatSpec {
+///     alignment: Alignment::CenterLeft,
+///     pad_block: '-',
+///     total_width: 12,
+///     excess_policy: ExcessPolicy::Ellipsize,
+/// });
+/// assert_eq!(spec.pad("\u{4F60}\u{597D}").to_string(), "----\u{4F60}\u{597D}----");
+/// assert_eq!(spec.pad("a long long value").to_string(), "a long long…");
+/// assert_eq!(spec.to_string(), "^-12.");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatSpec {
+    /// How to pad.
+    pub alignment: Alignment,
+    /// Block of the pad.
+    pub pad_block: char,
+    /// Total width to fulfill.
+    pub total_width: usize,
+    /// What to do when the width of a value exceeds `total_width`.
+    pub excess_policy: ExcessPolicy,
+}
+
+impl FormatSpec {
+    /// Pad a value according to the specification.
+    pub fn pad<Value: Width>(
+        &self,
+        value: Value,
+    ) -> PaddedValue<Value, char, ExcessPolicy, Alignment> {
+        PaddedValue {
+            value,
+            pad_block: self.pad_block,
+            total_width: self.total_width,
+            pad: self.alignment,
+            handle_excess: self.excess_policy,
+        }
+    }
+}
+
+/// Find the alignment that is represented by a character.
+const fn alignment_from_symbol(symbol: char) -> Option<Alignment> {
+    Some(match symbol {
+        '<' => Alignment::Left,
+        '>' => Alignment::Right,
+        '^' => Alignment::CenterLeft,
+        '~' => Alignment::CenterRight,
+        '=' => Alignment::JustifyBiasLeft,
+        '#' => Alignment::JustifyBiasRight,
+        _ => return None,
+    })
+}
+
+/// Character that represents an alignment in a [`FormatSpec`].
+fn alignment_symbol(alignment: Alignment) -> char {
+    match alignment {
+        Alignment::Left => '<',
+        Alignment::Right => '>',
+        Alignment::CenterLeft => '^',
+        Alignment::CenterRight => '~',
+        Alignment::JustifyBiasLeft => '=',
+        Alignment::JustifyBiasRight => '#',
+    }
+}
+
+/// Error of [`parse_spec`] along with the byte offset where it occurs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SpecError {
+    MissingWidth(usize),
+    WidthOverflow(usize),
+    UnexpectedCharacter(usize),
+}
+
+/// Number of bytes of the UTF-8 character that starts with `byte`.
+pub(crate) const fn utf8_len(byte: u8) -> usize {
+    match byte {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+/// Parse `bytes[start..end]` as a [`FormatSpec`].
+///
+/// A `const fn` cannot decode a `char`, so the pad block of the returned spec is always a space
+/// and the byte offset of the fill (if any) is returned alongside it.
+pub(crate) const fn parse_spec(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+) -> Result<(FormatSpec, Option<usize>), SpecError> {
+    let mut index = start;
+
+    let mut alignment = Alignment::Left;
+    let mut fill = None;
+    if index < end {
+        if let Some(symbol) = alignment_from_symbol(bytes[index] as char) {
+            alignment = symbol;
+            index += 1;
+            if index + 1 < end && bytes[index] == b'\\' {
+                let escaped = index + 1;
+                let after_fill = escaped + utf8_len(bytes[escaped]);
+                if after_fill < end && bytes[after_fill].is_ascii_digit() {
+                    fill = Some(escaped);
+                    index = after_fill;
+                }
+            }
+            if fill.is_none() && index < end && !bytes[index].is_ascii_digit() {
+                let after_fill = index + utf8_len(bytes[index]);
+                if after_fill < end && bytes[after_fill].is_ascii_digit() {
+                    fill = Some(index);
+                    index = after_fill;
+                }
+            }
+        }
+    }
+
+    let width_start = index;
+    let mut total_width: usize = 0;
+    while index < end && bytes[index].is_ascii_digit() {
+        let digit = (bytes[index] - b'0') as usize;
+        total_width = match total_width.checked_mul(10) {
+            Some(total_width) => match total_width.checked_add(digit) {
+                Some(total_width) => total_width,
+                None => return Err(SpecError::WidthOverflow(width_start)),
+            },
+            None => return Err(SpecError::WidthOverflow(width_start)),
+        };
+        index += 1;
+    }
+    if index == width_start {
+        return Err(SpecError::MissingWidth(width_start));
+    }
+
+    let mut excess_policy = ExcessPolicy::Ignore;
+    if index < end {
+        excess_policy = match ExcessPolicy::from_symbol(bytes[index] as char) {
+            Some(excess_policy) => excess_policy,
+            None => return Err(SpecError::UnexpectedCharacter(index)),
+        };
+        index += 1;
+    }
+    if index < end {
+        return Err(SpecError::UnexpectedCharacter(index));
+    }
+
+    let spec = FormatSpec {
+        alignment,
+        pad_block: ' ',
+        total_width,
+        excess_policy,
+    };
+    Ok((spec, fill))
+}
+
+/// Character of `text` that starts at a byte offset.
+pub(crate) fn char_at(text: &str, offset: usize) -> char {
+    text[offset..].chars().next().unwrap_or(' ')
+}
+
+impl FromStr for FormatSpec {
+    type Err = ParseFormatSpecError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match parse_spec(text.as_bytes(), 0, text.len()) {
+            Ok((mut spec, fill)) => {
+                if let Some(fill) = fill {
+                    spec.pad_block = char_at(text, fill);
+                }
+                Ok(spec)
+            }
+            Err(SpecError::MissingWidth(_)) => Err(ParseFormatSpecError::MissingWidth),
+            Err(SpecError::WidthOverflow(_)) => Err(ParseFormatSpecError::WidthOverflow),
+            Err(SpecError::UnexpectedCharacter(offset)) => Err(
+                ParseFormatSpecError::UnexpectedCharacter(char_at(text, offset)),
+            ),
+        }
+    }
+}
+
+impl Display for FormatSpec {
+    /// Write the spec in the syntax that [`FromStr`] parses.
+    ///
+    /// A `pad_block` that is an ASCII digit or a backslash is escaped with a backslash.
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(formatter, "{}", alignment_symbol(self.alignment))?;
+        if self.pad_block.is_ascii_digit() || self.pad_block == '\\' {
+            write!(formatter, "\\")?;
+        }
+        write!(formatter, "{}{}", self.pad_block, self.total_width)?;
+        if let Some(symbol) = self.excess_policy.symbol() {
+            write!(formatter, "{}", symbol)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+#![cfg(feature = "std")]
+
+use crate::{Alignment, PaddedColumnIter, PaddedValue, PanicOnExcess, Width};
+use derive_builder::Builder;
+
+/// Pad values of a (possibly endless) stream while reading only a few values ahead.
+///
+/// Up to `window` values are buffered before a value is yielded, and the total width
+/// is the maximum width of all values that have been read so far. The total width
+/// therefore never shrinks, so rows that were already yielded stay lined up with later ones.
+///
+/// A `window` of 0 is treated as 1.
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`IntoIterator`]: Build an iterator of padded values.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{AlignRight, StreamingColumn};
+/// let values = ["a", "bbb", "cc", "d", "eeeee", "f"];
+/// let streaming_column = StreamingColumn {
+///     values: values.iter(),
+///     pad_block: '.',
+///     pad: AlignRight,
+///     window: 2,
+/// };
+/// let padded_values: Vec<_> = streaming_column
+///     .into_iter()
+///     .map(|x| x.to_string())
+///     .collect();
+/// let expected = [
+///     "..a", "bbb", ".cc", "....d", "eeeee", "....f",
+/// ];
+/// assert_eq!(padded_values, expected);
+/// ```
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct StreamingColumn<ValueIter, PadBlock = char, Pad = Alignment>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    /// Values to be padded.
+    pub values: ValueIter,
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
+    pub pad_block: PadBlock,
+    /// Where to place the pad.
+    pub pad: Pad,
+    /// Number of values to read ahead before yielding a value.
+    pub window: usize,
+}
+
+impl<ValueIter, PadBlock, Pad> IntoIterator for StreamingColumn<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    type Item = PaddedValue<ValueIter::Item, PadBlock, PanicOnExcess, Pad>;
+    type IntoIter = StreamingColumnIter<ValueIter, PadBlock, Pad>;
+    fn into_iter(self) -> Self::IntoIter {
+        let StreamingColumn {
+            values,
+            pad_block,
+            pad,
+            window,
+        } = self;
+        StreamingColumnIter {
+            values,
+            buffer: PaddedColumnIter::new(pad_block, pad),
+            window: window.max(1),
+        }
+    }
+}
+
+/// Iterator created by calling [`into_iter`](IntoIterator::into_iter) on [`StreamingColumn`].
+///
+/// **Required features:** `std`
+#[derive(Debug, Clone)]
+pub struct StreamingColumnIter<ValueIter, PadBlock = char, Pad = Alignment>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    values: ValueIter,
+    buffer: PaddedColumnIter<ValueIter::Item, PadBlock, Pad>,
+    window: usize,
+}
+
+impl<ValueIter, PadBlock, Pad> StreamingColumnIter<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    /// Pad block that was used in the construction of [`StreamingColumn`].
+    pub fn pad_block(&self) -> PadBlock {
+        self.buffer.pad_block()
+    }
+
+    /// Padding method that was used in the construction of [`StreamingColumn`].
+    pub fn pad(&self) -> Pad {
+        self.buffer.pad()
+    }
+
+    /// Maximum width of all items that have been read so far.
+    pub fn total_width(&self) -> usize {
+        self.buffer.total_width()
+    }
+
+    /// Number of values that are read ahead before a value is yielded.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Number of values that have been read but not yet yielded.
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+impl<ValueIter, PadBlock, Pad> Iterator for StreamingColumnIter<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    type Item = PaddedValue<ValueIter::Item, PadBlock, PanicOnExcess, Pad>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.len() < self.window {
+            match self.values.next() {
+                Some(value) => self.buffer.push_back(value),
+                None => break,
+            }
+        }
+        self.buffer.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered_len = self.buffer.len();
+        let (min, max) = self.values.size_hint();
+        (
+            min.saturating_add(buffered_len),
+            max.and_then(|max| max.checked_add(buffered_len)),
+        )
+    }
+}
@@ -0,0 +1,314 @@
+#![cfg(feature = "std")]
+
+use crate::{Alignment, PaddedValue, PanicOnExcess, Width};
+use derive_builder::Builder;
+use std::{
+    collections::LinkedList,
+    fmt::{Display, Error, Formatter},
+};
+
+/// Pad all values in a table so that values of the same column share the same (maximum) width.
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`IntoIterator`]: Build an iterator of padded rows.
+///
+/// Columns that have no corresponding item in `pads` are padded with `Pad::default()`.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{PaddedTable, Alignment};
+/// let rows = vec![
+///     vec!["Language", "Typing", "Year"],
+///     vec!["Rust", "static", "2010"],
+///     vec!["JavaScript", "dynamic", "1995"],
+///     vec!["Go", "static", "2009"],
+/// ];
+/// let padded_table = PaddedTable {
+///     rows: rows.iter(),
+///     pad_block: ' ',
+///     pads: vec![Alignment::Left, Alignment::CenterLeft, Alignment::Right],
+///     separator: " | ",
+/// };
+/// let padded_rows: Vec<_> = padded_table
+///     .into_iter()
+///     .map(|x| x.to_string())
+///     .collect();
+/// let expected = [
+///     "Language   | Typing  | Year",
+///     "Rust       | static  | 2010",
+///     "JavaScript | dynamic | 1995",
+///     "Go         | static  | 2009",
+/// ];
+/// assert_eq!(padded_rows, expected);
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct PaddedTable<RowIter, PadBlock = char, Pad = Alignment, Separator = &'static str>
+where
+    RowIter: Iterator,
+    RowIter::Item: IntoIterator,
+    <RowIter::Item as IntoIterator>::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<<RowIter::Item as IntoIterator>::Item, PadBlock> + Default + Copy,
+    Separator: Width + Copy,
+{
+    /// Rows of values to be padded.
+    pub rows: RowIter,
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
+    pub pad_block: PadBlock,
+    /// Where to place the pad of each column.
+    pub pads: Vec<Pad>,
+    /// Value to be placed between every two adjacent cells of a row.
+    pub separator: Separator,
+}
+
+impl<RowIter, PadBlock, Pad, Separator> IntoIterator
+    for PaddedTable<RowIter, PadBlock, Pad, Separator>
+where
+    RowIter: Iterator,
+    RowIter::Item: IntoIterator,
+    <RowIter::Item as IntoIterator>::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<<RowIter::Item as IntoIterator>::Item, PadBlock> + Default + Copy,
+    Separator: Width + Copy,
+{
+    type Item = PaddedRow<<RowIter::Item as IntoIterator>::Item, PadBlock, Pad, Separator>;
+    type IntoIter =
+        PaddedTableIter<<RowIter::Item as IntoIterator>::Item, PadBlock, Pad, Separator>;
+    fn into_iter(self) -> Self::IntoIter {
+        let PaddedTable {
+            rows,
+            pad_block,
+            pads,
+            separator,
+        } = self;
+        let mut iter = PaddedTableIter::new(pad_block, pads, separator);
+        iter.extend(rows);
+        iter
+    }
+}
+
+/// Iterator created by calling [`into_iter`](IntoIterator::into_iter) on [`PaddedTable`].
+///
+/// **Required features:** `std`
+#[derive(Debug, Clone)]
+pub struct PaddedTableIter<Value, PadBlock = char, Pad = Alignment, Separator = &'static str>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Default + Copy,
+    Separator: Width + Copy,
+{
+    row_list: LinkedList<Vec<Value>>,
+    pad_block: PadBlock,
+    pads: Vec<Pad>,
+    separator: Separator,
+    column_widths: Vec<usize>,
+}
+
+impl<Value, PadBlock, Pad, Separator> PaddedTableIter<Value, PadBlock, Pad, Separator>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Default + Copy,
+    Separator: Width + Copy,
+{
+    /// Initialize an empty iterator.
+    pub fn new(pad_block: PadBlock, pads: Vec<Pad>, separator: Separator) -> Self {
+        PaddedTableIter {
+            row_list: LinkedList::new(),
+            column_widths: Vec::new(),
+            pad_block,
+            pads,
+            separator,
+        }
+    }
+
+    /// Add a row to the table.
+    /// If width of a value in the new row is greater than the current width of its column,
+    /// set it as the new width of the column.
+    pub fn push_back<Row: IntoIterator<Item = Value>>(&mut self, row: Row) {
+        let row: Vec<Value> = row.into_iter().collect();
+        for (index, value) in row.iter().enumerate() {
+            let width = value.width();
+            match self.column_widths.get_mut(index) {
+                Some(column_width) if *column_width < width => *column_width = width,
+                Some(_) => {}
+                None => self.column_widths.push(width),
+            }
+        }
+        self.row_list.push_back(row);
+    }
+
+    /// Pad block that was used in the construction of [`PaddedTable`].
+    pub fn pad_block(&self) -> PadBlock {
+        self.pad_block
+    }
+
+    /// Padding methods that were used in the construction of [`PaddedTable`].
+    pub fn pads(&self) -> &'_ [Pad] {
+        &self.pads
+    }
+
+    /// Separator that was used in the construction of [`PaddedTable`].
+    pub fn separator(&self) -> Separator {
+        self.separator
+    }
+
+    /// Maximum width of all values of each column that were passed to [`PaddedTable`].
+    pub fn column_widths(&self) -> &'_ [usize] {
+        &self.column_widths
+    }
+
+    /// Width of a row that has values in every column.
+    pub fn total_width(&self) -> usize {
+        let column_count = self.column_widths.len();
+        let separator_count = column_count.saturating_sub(1);
+        self.column_widths.iter().sum::<usize>() + separator_count * self.separator.width()
+    }
+}
+
+impl<Value, PadBlock, Pad, Separator> Iterator for PaddedTableIter<Value, PadBlock, Pad, Separator>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Default + Copy,
+    Separator: Width + Copy,
+{
+    type Item = PaddedRow<Value, PadBlock, Pad, Separator>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let PaddedTableIter {
+            row_list,
+            pad_block,
+            pads,
+            separator,
+            column_widths,
+        } = self;
+        row_list.pop_front().map(|row| PaddedRow {
+            cells: row
+                .into_iter()
+                .zip(column_widths.iter())
+                .enumerate()
+                .map(|(index, (value, total_width))| PaddedValue {
+                    value,
+                    pad_block: *pad_block,
+                    pad: pads.get(index).copied().unwrap_or_default(),
+                    total_width: *total_width,
+                    handle_excess: PanicOnExcess,
+                })
+                .collect(),
+            separator: *separator,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<Value, PadBlock, Pad, Separator> ExactSizeIterator
+    for PaddedTableIter<Value, PadBlock, Pad, Separator>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Default + Copy,
+    Separator: Width + Copy,
+{
+    fn len(&self) -> usize {
+        self.row_list.len()
+    }
+}
+
+impl<Row, Value, PadBlock, Pad, Separator> Extend<Row>
+    for PaddedTableIter<Value, PadBlock, Pad, Separator>
+where
+    Row: IntoIterator<Item = Value>,
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Default + Copy,
+    Separator: Width + Copy,
+{
+    fn extend<Rows: IntoIterator<Item = Row>>(&mut self, rows: Rows) {
+        for row in rows {
+            self.push_back(row);
+        }
+    }
+}
+
+/// A row of padded values, yielded by [`PaddedTableIter`].
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`Display`]: Displays the padded values of the row with separators in between.
+#[derive(Debug, Clone)]
+pub struct PaddedRow<Value, PadBlock = char, Pad = Alignment, Separator = &'static str>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+    Separator: Width + Copy,
+{
+    cells: Vec<PaddedValue<Value, PadBlock, PanicOnExcess, Pad>>,
+    separator: Separator,
+}
+
+impl<Value, PadBlock, Pad, Separator> PaddedRow<Value, PadBlock, Pad, Separator>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+    Separator: Width + Copy,
+{
+    /// Padded values of the row.
+    pub fn cells(&self) -> &'_ [PaddedValue<Value, PadBlock, PanicOnExcess, Pad>] {
+        &self.cells
+    }
+
+    /// Extract the padded values of the row.
+    pub fn into_cells(self) -> Vec<PaddedValue<Value, PadBlock, PanicOnExcess, Pad>> {
+        self.cells
+    }
+
+    /// Separator between every two adjacent cells.
+    pub fn separator(&self) -> Separator {
+        self.separator
+    }
+}
+
+impl<Value, PadBlock, Pad, Separator> Display for PaddedRow<Value, PadBlock, Pad, Separator>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+    Separator: Width + Copy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        for (index, cell) in self.cells.iter().enumerate() {
+            if index != 0 {
+                write!(formatter, "{}", self.separator)?;
+            }
+            write!(formatter, "{}", cell)?;
+        }
+        Ok(())
+    }
+}
+
+impl<Value, PadBlock, Pad, Separator> Width for PaddedRow<Value, PadBlock, Pad, Separator>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<Value, PadBlock> + Copy,
+    Separator: Width + Copy,
+{
+    fn width(&self) -> usize {
+        let cells_width: usize = self.cells.iter().map(Width::width).sum();
+        let separator_count = self.cells.len().saturating_sub(1);
+        cells_width + separator_count * self.separator.width()
+    }
+}
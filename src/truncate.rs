@@ -0,0 +1,227 @@
+use crate::{ansi::AnsiParser, EllipsisPosition, Width};
+use core::fmt::{Display, Error, Formatter, Write};
+use unicode_width::UnicodeWidthChar;
+
+/// [`Write`] adapter that forwards every character to a callback.
+struct CharSink<Callback>(Callback)
+where
+    Callback: FnMut(char) -> Result<(), Error>;
+
+impl<Callback> Write for CharSink<Callback>
+where
+    Callback: FnMut(char) -> Result<(), Error>,
+{
+    fn write_str(&mut self, text: &str) -> Result<(), Error> {
+        text.chars().try_for_each(&mut self.0)
+    }
+}
+
+/// Stream the displayed characters of a value into a callback.
+pub(crate) fn for_each_display_char<Value, Callback>(
+    value: &Value,
+    callback: Callback,
+) -> Result<(), Error>
+where
+    Value: Display + ?Sized,
+    Callback: FnMut(char) -> Result<(), Error>,
+{
+    write!(CharSink(callback), "{}", value)
+}
+
+/// Stream the displayed characters of a value into a callback along with their widths.
+///
+/// Characters of ANSI escape sequences are reported as [`None`].
+pub(crate) fn for_each_char<Value, Callback>(
+    value: &Value,
+    mut callback: Callback,
+) -> Result<(), Error>
+where
+    Value: Display + ?Sized,
+    Callback: FnMut(char, Option<usize>) -> Result<(), Error>,
+{
+    let mut parser = AnsiParser::new();
+    for_each_display_char(value, |character| {
+        if parser.is_visible(character) {
+            callback(
+                character,
+                Some(UnicodeWidthChar::width(character).unwrap_or(0)),
+            )
+        } else {
+            callback(character, None)
+        }
+    })
+}
+
+/// Measure the width of the displayed characters of a value.
+pub(crate) fn display_width<Value: Display + ?Sized>(value: &Value) -> usize {
+    let mut total = 0;
+    let _ = for_each_char(value, |_, width| {
+        total += width.unwrap_or(0);
+        Ok(())
+    });
+    total
+}
+
+/// Write the displayed characters of a value that fall within a range of columns.
+///
+/// * Columns are counted by [`Width::for_each_char_width`].
+/// * Skip the first `skip` columns then write at most `take` columns.
+/// * Wide characters that cross a boundary of the range are replaced by pad blocks.
+/// * Characters that are not measured (such as ANSI escape sequences) are always written.
+///
+/// Return the number of written columns.
+pub(crate) fn write_columns<Value, PadBlock>(
+    formatter: &mut Formatter<'_>,
+    value: &Value,
+    pad_block: &PadBlock,
+    skip: usize,
+    take: usize,
+) -> Result<usize, Error>
+where
+    Value: Width + ?Sized,
+    PadBlock: crate::PadBlock,
+{
+    let end_of_range = skip + take;
+    let mut position = 0;
+    let mut written = 0;
+    let mut last_written = skip == 0 && take != 0;
+    value.for_each_char_width(&mut |character, width| {
+        let width = match width {
+            None => return formatter.write_char(character),
+            Some(0) if last_written => return formatter.write_char(character),
+            Some(0) => return Ok(()),
+            Some(width) => width,
+        };
+        let start = position;
+        let end = start + width;
+        position = end;
+        if start >= skip && end <= end_of_range {
+            written += width;
+            last_written = true;
+            return formatter.write_char(character);
+        }
+        last_written = false;
+        let overlap = end.min(end_of_range).saturating_sub(start.max(skip));
+        written += overlap;
+        pad_block.fill(formatter, overlap)
+    })?;
+    Ok(written)
+}
+
+/// Fill the columns that were not written with pad blocks.
+fn fill<PadBlock: crate::PadBlock>(
+    formatter: &mut Formatter<'_>,
+    pad_block: &PadBlock,
+    written: usize,
+    total_width: usize,
+) -> Result<(), Error> {
+    let remainder = total_width.saturating_sub(written);
+    pad_block.fill_right(formatter, remainder)
+}
+
+/// Write the last `total_width` columns of a value.
+pub(crate) fn truncate_start<Value, PadBlock>(
+    formatter: &mut Formatter<'_>,
+    value: &Value,
+    pad_block: &PadBlock,
+    total_width: usize,
+) -> Result<(), Error>
+where
+    Value: Width + ?Sized,
+    PadBlock: crate::PadBlock,
+{
+    let skip = value.width().saturating_sub(total_width);
+    let written = write_columns(formatter, value, pad_block, skip, total_width)?;
+    fill(formatter, pad_block, written, total_width)
+}
+
+/// Write the first `total_width` columns of a value.
+pub(crate) fn truncate_end<Value, PadBlock>(
+    formatter: &mut Formatter<'_>,
+    value: &Value,
+    pad_block: &PadBlock,
+    total_width: usize,
+) -> Result<(), Error>
+where
+    Value: Width + ?Sized,
+    PadBlock: crate::PadBlock,
+{
+    let written = write_columns(formatter, value, pad_block, 0, total_width)?;
+    fill(formatter, pad_block, written, total_width)
+}
+
+/// Write the first and the last columns of a value so that the total is `total_width`.
+///
+/// The first part takes the remainder column (if any).
+pub(crate) fn truncate_middle<Value, PadBlock>(
+    formatter: &mut Formatter<'_>,
+    value: &Value,
+    pad_block: &PadBlock,
+    total_width: usize,
+) -> Result<(), Error>
+where
+    Value: Width + ?Sized,
+    PadBlock: crate::PadBlock,
+{
+    let value_width = value.width();
+    if value_width <= total_width {
+        return truncate_end(formatter, value, pad_block, total_width);
+    }
+    let head_width = total_width - total_width / 2;
+    let tail_width = total_width / 2;
+    let head = write_columns(formatter, value, pad_block, 0, head_width)?;
+    let tail_start = value_width - tail_width;
+    let tail = write_columns(formatter, value, pad_block, tail_start, tail_width)?;
+    fill(formatter, pad_block, head + tail, total_width)
+}
+
+/// Replace the excessive part of a value with a marker so that the total is `total_width`.
+///
+/// If the marker itself does not fit, truncate the value without it.
+pub(crate) fn ellipsize<Value, PadBlock, Marker>(
+    formatter: &mut Formatter<'_>,
+    value: &Value,
+    pad_block: &PadBlock,
+    total_width: usize,
+    marker: &Marker,
+    position: EllipsisPosition,
+) -> Result<(), Error>
+where
+    Value: Width + ?Sized,
+    PadBlock: crate::PadBlock,
+    Marker: Width,
+{
+    let value_width = value.width();
+    if value_width <= total_width {
+        return truncate_end(formatter, value, pad_block, total_width);
+    }
+    let marker_width = marker.width();
+    if marker_width > total_width {
+        return match position {
+            EllipsisPosition::Start => truncate_start(formatter, value, pad_block, total_width),
+            EllipsisPosition::Middle => truncate_middle(formatter, value, pad_block, total_width),
+            EllipsisPosition::End => truncate_end(formatter, value, pad_block, total_width),
+        };
+    }
+    let available_width = total_width - marker_width;
+    match position {
+        EllipsisPosition::Start => {
+            write!(formatter, "{}", marker)?;
+            truncate_start(formatter, value, pad_block, available_width)
+        }
+        EllipsisPosition::Middle => {
+            let head_width = available_width - available_width / 2;
+            let tail_width = available_width / 2;
+            let head = write_columns(formatter, value, pad_block, 0, head_width)?;
+            fill(formatter, pad_block, head, head_width)?;
+            write!(formatter, "{}", marker)?;
+            let tail_start = value_width - tail_width;
+            let tail = write_columns(formatter, value, pad_block, tail_start, tail_width)?;
+            fill(formatter, pad_block, tail, tail_width)
+        }
+        EllipsisPosition::End => {
+            truncate_end(formatter, value, pad_block, available_width)?;
+            write!(formatter, "{}", marker)
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{Alignment, PaddedValue, PanicOnExcess, Width};
+use fmt_iter::FmtIter;
+
+/// Pad all values in a re-iterable collection to be of same (maximum) width without heap allocation.
+///
+/// The first pass over a clone of `values` computes the maximum width,
+/// the second pass emits the padded values.
+///
+/// **Key traits:**
+/// * [`IntoIterator`]: Build an iterator of padded values.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{TwoPassColumn, AlignRight};
+/// let values = [
+///     "Rust", "C", "C++", "C#", "JavaScript",
+///     "TypeScript", "Java", "Kotlin", "Go",
+/// ];
+/// let two_pass_column = TwoPassColumn {
+///     values: values.iter(),
+///     pad_block: ' ',
+///     pad: AlignRight,
+/// };
+/// let padded_values: Vec<_> = two_pass_column
+///     .into_iter()
+///     .map(|x| x.to_string())
+///     .collect();
+/// let expected = [
+///     "      Rust", "         C", "       C++",
+///     "        C#", "JavaScript", "TypeScript",
+///     "      Java", "    Kotlin", "        Go",
+/// ];
+/// assert_eq!(padded_values, expected);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TwoPassColumn<ValueIter, PadBlock = char, Pad = Alignment>
+where
+    ValueIter: Iterator + Clone,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    /// Values to be padded.
+    pub values: ValueIter,
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
+    pub pad_block: PadBlock,
+    /// Where to place the pad.
+    pub pad: Pad,
+}
+
+impl<ValueIter, PadBlock, Pad> IntoIterator for TwoPassColumn<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator + Clone,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    type Item = PaddedValue<ValueIter::Item, PadBlock, PanicOnExcess, Pad>;
+    type IntoIter = TwoPassColumnIter<ValueIter, PadBlock, Pad>;
+    fn into_iter(self) -> Self::IntoIter {
+        let TwoPassColumn {
+            values,
+            pad_block,
+            pad,
+        } = self;
+        let total_width = values.clone().map(|value| value.width()).max().unwrap_or(0);
+        TwoPassColumnIter {
+            values,
+            pad_block,
+            pad,
+            total_width,
+        }
+    }
+}
+
+/// Iterator created by calling [`into_iter`](IntoIterator::into_iter) on [`TwoPassColumn`].
+#[derive(Debug, Clone, Copy)]
+pub struct TwoPassColumnIter<ValueIter, PadBlock = char, Pad = Alignment>
+where
+    ValueIter: Iterator + Clone,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    values: ValueIter,
+    pad_block: PadBlock,
+    pad: Pad,
+    total_width: usize,
+}
+
+impl<ValueIter, PadBlock, Pad> TwoPassColumnIter<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator + Clone,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    /// Pad block that was used in the construction of [`TwoPassColumn`].
+    pub fn pad_block(&self) -> PadBlock {
+        self.pad_block
+    }
+
+    /// Padding method that was used in the construction of [`TwoPassColumn`].
+    pub fn pad(&self) -> Pad {
+        self.pad
+    }
+
+    /// Maximum width of all items that were passed to [`TwoPassColumn`].
+    pub fn total_width(&self) -> usize {
+        self.total_width
+    }
+}
+
+impl<ValueIter, PadBlock, Pad> Iterator for TwoPassColumnIter<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator + Clone,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    type Item = PaddedValue<ValueIter::Item, PadBlock, PanicOnExcess, Pad>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let TwoPassColumnIter {
+            values,
+            pad_block,
+            pad,
+            total_width,
+        } = self;
+        values.next().map(|value| PaddedValue {
+            value,
+            pad_block: *pad_block,
+            pad: *pad,
+            total_width: *total_width,
+            handle_excess: PanicOnExcess,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<ValueIter, PadBlock, Pad> ExactSizeIterator for TwoPassColumnIter<ValueIter, PadBlock, Pad>
+where
+    ValueIter: ExactSizeIterator + Clone,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<ValueIter, PadBlock, Pad> Width for FmtIter<TwoPassColumnIter<ValueIter, PadBlock, Pad>>
+where
+    ValueIter: ExactSizeIterator + Clone,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<ValueIter::Item, PadBlock> + Copy,
+{
+    fn width(&self) -> usize {
+        self.total_width() * self.len()
+    }
+}
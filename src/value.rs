@@ -1,5 +1,7 @@
-use crate::{Alignment, Excess, ExcessHandler, ExcessHandlingFunction, Width};
-use core::fmt::{Display, Error, Formatter};
+use crate::{
+    Alignment, Excess, ExcessError, ExcessHandler, ExcessHandlingFunction, RenderError, Width,
+};
+use core::fmt::{Display, Error, Formatter, Write};
 
 #[cfg(feature = "std")]
 use derive_builder::Builder;
@@ -51,13 +53,13 @@ pub struct PaddedValue<
     Pad = Alignment,
 > where
     Value: Width,
-    PadBlock: Display,
+    PadBlock: crate::PadBlock,
     HandleExcess: ExcessHandler<Value, PadBlock>,
     Pad: crate::Pad<Value, PadBlock>,
 {
     /// Value to be padded.
     pub value: Value,
-    /// Block of the pad (expected to have width of 1).
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
     pub pad_block: PadBlock,
     /// Total width to fulfill.
     pub total_width: usize,
@@ -67,10 +69,88 @@ pub struct PaddedValue<
     pub handle_excess: HandleExcess,
 }
 
+impl<Value, PadBlock, HandleExcess, Pad> PaddedValue<Value, PadBlock, HandleExcess, Pad>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock,
+    HandleExcess: ExcessHandler<Value, PadBlock>,
+    Pad: crate::Pad<Value, PadBlock>,
+{
+    /// Check whether the width of `value` fits in `total_width`.
+    ///
+    /// **Example:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{align_right, ExcessError};
+    /// assert_eq!(align_right("abc", 5).check(), Ok(()));
+    /// assert_eq!(
+    ///     align_right("abcdefghi", 5).check(),
+    ///     Err(ExcessError {
+    ///         value_width: 9,
+    ///         total_width: 5,
+    ///     }),
+    /// );
+    /// ```
+    pub fn check(&self) -> Result<(), ExcessError> {
+        let value_width = self.value.width();
+        if value_width > self.total_width {
+            return Err(ExcessError {
+                value_width,
+                total_width: self.total_width,
+            });
+        }
+        Ok(())
+    }
+
+    /// Write the padded value to a writer.
+    ///
+    /// Unlike [`Display`], an excess does not invoke `handle_excess`
+    /// but returns a [`RenderError::Excess`] without writing anything.
+    ///
+    /// **Example:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{align_right, ExcessError, RenderError};
+    /// let mut output = String::new();
+    /// align_right("abc", 5).try_render(&mut output).unwrap();
+    /// assert_eq!(output, "  abc");
+    /// let error = align_right("abcdefghi", 5)
+    ///     .try_render(&mut output)
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     error,
+    ///     RenderError::Excess(ExcessError {
+    ///         value_width: 9,
+    ///         total_width: 5,
+    ///     }),
+    /// );
+    /// assert_eq!(error.to_string(), "value's width (9) is greater than total_width (5)");
+    /// ```
+    pub fn try_render<Writer: Write>(&self, writer: &mut Writer) -> Result<(), RenderError> {
+        self.check()?;
+        write!(writer, "{}", self)?;
+        Ok(())
+    }
+
+    /// Render the padded value into a [`String`].
+    ///
+    /// **Required features:** `std`
+    ///
+    /// see [`try_render`](PaddedValue::try_render).
+    #[cfg(feature = "std")]
+    pub fn try_to_string(&self) -> Result<String, RenderError> {
+        let mut output = String::new();
+        self.try_render(&mut output)?;
+        Ok(output)
+    }
+}
+
 impl<Value, PadBlock, HandleExcess, Pad> Display for PaddedValue<Value, PadBlock, HandleExcess, Pad>
 where
     Value: Width,
-    PadBlock: Display,
+    PadBlock: crate::PadBlock,
     HandleExcess: ExcessHandler<Value, PadBlock>,
     Pad: crate::Pad<Value, PadBlock>,
 {
@@ -104,7 +184,7 @@ where
 impl<Value, PadBlock, HandleExcess, Pad> Width for PaddedValue<Value, PadBlock, HandleExcess, Pad>
 where
     Value: Width,
-    PadBlock: Display,
+    PadBlock: crate::PadBlock,
     HandleExcess: ExcessHandler<Value, PadBlock>,
     Pad: crate::Pad<Value, PadBlock>,
 {
@@ -1,12 +1,39 @@
 pub use unicode_width::{UnicodeWidthChar, UnicodeWidthStr, UNICODE_VERSION};
 
+#[cfg(feature = "grapheme")]
+use crate::grapheme::{grapheme_char_widths, grapheme_width};
+use crate::{
+    ansi::{ansi_char_widths, ansi_unicode_width},
+    truncate::for_each_display_char,
+};
 use core::fmt::{Display, Error, Formatter};
 use derive_more::{AsMut, AsRef, Deref, DerefMut, From};
 
+/// Callback of [`Width::for_each_char_width`].
+pub type CharWidthCallback<'a> = &'a mut dyn FnMut(char, Option<usize>) -> Result<(), Error>;
+
 /// Value that has width.
 pub trait Width: Display {
     /// Get width of the value.
     fn width(&self) -> usize;
+
+    /// Stream the displayed characters of the value along with the width that
+    /// [`width`](Width::width) counts for each of them.
+    ///
+    /// Excess handlers that cut values, such as [`TruncateEnd`](crate::TruncateEnd),
+    /// use it to measure the kept part the same way as the whole value:
+    /// * `Some(width)`: A character that takes `width` columns.
+    ///   A character of width `Some(0)` is kept only along with the character before it.
+    /// * `None`: A character that is not measured at all (such as a part of an ANSI escape sequence),
+    ///   it is always kept.
+    ///
+    /// The default implementation reports [`UnicodeWidthChar::width`] of every character,
+    /// scaled so that the reported widths add up to [`width`](Width::width).
+    /// Override it when `width` weighs characters differently than [`UnicodeWidthChar::width`] does
+    /// (such as [`AnsiUnicodeWidth`], which does not measure escape sequences).
+    fn for_each_char_width(&self, callback: CharWidthCallback<'_>) -> Result<(), Error> {
+        scaled_char_widths(self, callback)
+    }
 }
 
 impl Width for str {
@@ -28,16 +55,115 @@ impl Width for String {
     }
 }
 
+#[cfg(feature = "std")]
+impl<X: Width + ?Sized> Width for Box<X> {
+    fn width(&self) -> usize {
+        X::width(self)
+    }
+
+    fn for_each_char_width(&self, callback: CharWidthCallback<'_>) -> Result<(), Error> {
+        X::for_each_char_width(self, callback)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<X: Width + ?Sized> Width for std::rc::Rc<X> {
+    fn width(&self) -> usize {
+        X::width(self)
+    }
+
+    fn for_each_char_width(&self, callback: CharWidthCallback<'_>) -> Result<(), Error> {
+        X::for_each_char_width(self, callback)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<X: Width + ?Sized> Width for std::sync::Arc<X> {
+    fn width(&self) -> usize {
+        X::width(self)
+    }
+
+    fn for_each_char_width(&self, callback: CharWidthCallback<'_>) -> Result<(), Error> {
+        X::for_each_char_width(self, callback)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, X> Width for std::borrow::Cow<'a, X>
+where
+    X: Width + ToOwned + ?Sized,
+    X::Owned: Display,
+{
+    fn width(&self) -> usize {
+        X::width(self)
+    }
+
+    fn for_each_char_width(&self, callback: CharWidthCallback<'_>) -> Result<(), Error> {
+        X::for_each_char_width(self, callback)
+    }
+}
+
 impl<X: Width + Sized> Width for &X {
     fn width(&self) -> usize {
         X::width(*self)
     }
+
+    fn for_each_char_width(&self, callback: CharWidthCallback<'_>) -> Result<(), Error> {
+        X::for_each_char_width(*self, callback)
+    }
+}
+
+/// Width of a character as counted by [`UnicodeWidthStr::width`].
+fn unicode_char_width(character: char) -> usize {
+    UnicodeWidthChar::width(character).unwrap_or(0)
+}
+
+/// Default implementation of [`Width::for_each_char_width`].
+///
+/// Widths are scaled by their running sum so that rounding never loses a column.
+/// When no character has a width, the last character takes the whole width.
+fn scaled_char_widths<Value>(value: &Value, callback: CharWidthCallback<'_>) -> Result<(), Error>
+where
+    Value: Width + ?Sized,
+{
+    let mut unicode_width = 0;
+    let mut char_count = 0;
+    for_each_display_char(value, |character| {
+        unicode_width += unicode_char_width(character);
+        char_count += 1;
+        Ok(())
+    })?;
+    let width = value.width();
+    if unicode_width == width {
+        return for_each_display_char(value, |character| {
+            callback(character, Some(unicode_char_width(character)))
+        });
+    }
+    let mut index = 0;
+    let mut running_width = 0;
+    let mut reported_width = 0;
+    for_each_display_char(value, |character| {
+        index += 1;
+        let scaled_width = if unicode_width == 0 {
+            if index == char_count {
+                width
+            } else {
+                0
+            }
+        } else {
+            running_width += unicode_char_width(character);
+            running_width * width / unicode_width
+        };
+        let character_width = scaled_width - reported_width;
+        reported_width = scaled_width;
+        callback(character, Some(character_width))
+    })
 }
 
 macro_rules! wrapper {
     (
         $(#[$attributes:meta])*
-        $name:ident = $get_width:expr
+        $name:ident = $get_width:expr $(, $char_widths:expr)?
     ) => {
         $(#[$attributes])*
         #[derive(Debug, Clone, Copy, PartialEq, Eq, AsMut, AsRef, Deref, DerefMut, From)]
@@ -64,6 +190,15 @@ macro_rules! wrapper {
             fn width(&self) -> usize {
                 $get_width(self.as_str())
             }
+
+            $(
+                fn for_each_char_width(
+                    &self,
+                    callback: CharWidthCallback<'_>,
+                ) -> Result<(), Error> {
+                    $char_widths(self.as_str(), callback)
+                }
+            )?
         }
 
         impl<Inner: AsRef<str>> AsRef<str> for $name<Inner> {
@@ -87,15 +222,71 @@ wrapper! {
 
 wrapper! {
     /// Treat [`UnicodeWidthStr::width_cjk`] as width.
-    UnicodeWidthCjk = UnicodeWidthStr::width_cjk
+    UnicodeWidthCjk = UnicodeWidthStr::width_cjk, |text: &str, callback: CharWidthCallback| {
+        text.chars().try_for_each(|character| {
+            callback(character, Some(UnicodeWidthChar::width_cjk(character).unwrap_or(0)))
+        })
+    }
+}
+
+wrapper! {
+    /// Treat [`UnicodeWidthStr::width`] of the text without ANSI escape sequences as width.
+    ///
+    /// Control sequences (such as SGR color codes) and operating system commands
+    /// (such as OSC 8 hyperlinks) are skipped when measuring, but are still displayed.
+    ///
+    /// **Example:**
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{align_right, AnsiUnicodeWidth, Width};
+    /// let value = AnsiUnicodeWidth::from("\u{1B}[31mred\u{1B}[0m");
+    /// assert_eq!(value.width(), 3);
+    /// assert_eq!(
+    ///     align_right(value, 5).to_string(),
+    ///     "  \u{1B}[31mred\u{1B}[0m",
+    /// );
+    /// ```
+    AnsiUnicodeWidth = ansi_unicode_width, ansi_char_widths
+}
+
+#[cfg(feature = "grapheme")]
+wrapper! {
+    /// Treat the sum of widths of extended grapheme clusters as width.
+    ///
+    /// **Required features:** `grapheme`
+    ///
+    /// Each cluster is as wide as its first character, except that emoji presentation
+    /// selectors (`U+FE0F`) widen it to 2, text presentation selectors (`U+FE0E`) narrow
+    /// it to 1, and pairs of regional indicators (flags) are 2 columns wide.
+    /// This makes emoji ZWJ sequences, flags, skin tone modifiers and combining marks
+    /// occupy as many columns as modern terminals render them with.
+    ///
+    /// **Example:**
+    ///
+    /// ```
+    /// # #[cfg(feature = "grapheme")] fn main() {
+    /// # use pretty_assertions::assert_eq;
+    /// use zero_copy_pads::{align_left, GraphemeWidth, Width};
+    /// let value = GraphemeWidth::from("👨‍👩‍👧🇻🇳");
+    /// assert_eq!(value.width(), 4);
+    /// assert_eq!(align_left(value, 6).to_string(), "👨‍👩‍👧🇻🇳  ");
+    /// # }
+    /// # #[cfg(not(feature = "grapheme"))] fn main() {}
+    /// ```
+    GraphemeWidth = grapheme_width, grapheme_char_widths
 }
 
 wrapper! {
     /// Treat character count as width.
-    CharCount = |x: &str| x.chars().count()
+    CharCount = |x: &str| x.chars().count(), |text: &str, callback: CharWidthCallback| {
+        text.chars().try_for_each(|character| callback(character, Some(1)))
+    }
 }
 
 wrapper! {
     /// Treat `str::len` as width.
-    Len = str::len
+    Len = str::len, |text: &str, callback: CharWidthCallback| {
+        text.chars().try_for_each(|character| callback(character, Some(character.len_utf8())))
+    }
 }
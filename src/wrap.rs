@@ -0,0 +1,429 @@
+#![cfg(feature = "std")]
+
+use crate::{Alignment, CharWidthCallback, PaddedValue, TruncateEnd, Width};
+use std::{
+    fmt::{Display, Error, Formatter},
+    rc::Rc,
+};
+use unicode_linebreak::{linebreaks, BreakOpportunity};
+
+/// Wrap a single value onto multiple lines then pad every line.
+///
+/// Lines are broken at Unicode line break opportunities ([UAX #14]).
+/// Words that are wider than `total_width` are broken between characters.
+///
+/// The displayed characters of the value are measured once by [`Width::for_each_char_width`]
+/// when [`lines`](WrappedValue::lines) is called, every line is measured the same way.
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`Display`]: Displays the padded lines, separated by line feeds.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{WrappedValue, AlignCenterLeft};
+/// let wrapped_value = WrappedValue {
+///     value: "The quick brown fox jumps over the lazy dog",
+///     pad_block: '-',
+///     total_width: 12,
+///     pad: AlignCenterLeft,
+/// };
+/// assert_eq!(wrapped_value.line_count(), 4);
+/// let lines: Vec<_> = wrapped_value
+///     .lines()
+///     .map(|x| x.to_string())
+///     .collect();
+/// let expected = [
+///     "-The quick--",
+///     "-brown fox--",
+///     "-jumps over-",
+///     "the lazy dog",
+/// ];
+/// assert_eq!(lines, expected);
+/// ```
+///
+/// [UAX #14]: https://www.unicode.org/reports/tr14/
+#[derive(Debug, Clone, Copy)]
+pub struct WrappedValue<Value, PadBlock = char, Pad = Alignment>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy,
+{
+    /// Value to be wrapped.
+    pub value: Value,
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
+    pub pad_block: PadBlock,
+    /// Maximum width of every line, which is also the width every line is padded to.
+    pub total_width: usize,
+    /// How to pad every line.
+    pub pad: Pad,
+}
+
+impl<Value, PadBlock, Pad> WrappedValue<Value, PadBlock, Pad>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy,
+{
+    /// Iterate over the padded lines.
+    pub fn lines(&self) -> WrappedLines<PadBlock, Pad> {
+        WrappedLines {
+            text: Rc::new(MeasuredText::new(&self.value)),
+            position: 0,
+            next_break: 0,
+            finished: false,
+            pad_block: self.pad_block,
+            total_width: self.total_width,
+            pad: self.pad,
+        }
+    }
+
+    /// Number of lines the value is wrapped onto.
+    pub fn line_count(&self) -> usize {
+        self.lines().count()
+    }
+}
+
+impl<Value, PadBlock, Pad> Display for WrappedValue<Value, PadBlock, Pad>
+where
+    Value: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        for (index, line) in self.lines().enumerate() {
+            if index != 0 {
+                writeln!(formatter)?;
+            }
+            write!(formatter, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Displayed characters of a value, measured once for all of its lines.
+#[derive(Debug)]
+struct MeasuredText {
+    text: String,
+    /// Byte offset, character and width of every character of `text`.
+    chars: Vec<(usize, char, Option<usize>)>,
+    /// Total width of the characters before each index of `chars` (and of all of them).
+    running_widths: Vec<usize>,
+    /// Character index of every line break opportunity, and whether the break is mandatory.
+    breaks: Vec<(usize, bool)>,
+}
+
+impl MeasuredText {
+    fn new<Value: Width>(value: &Value) -> Self {
+        let mut text = String::new();
+        let mut chars = Vec::new();
+        let mut running_widths = vec![0];
+        let mut total_width = 0;
+        let _ = value.for_each_char_width(&mut |character, width| {
+            chars.push((text.len(), character, width));
+            text.push(character);
+            total_width += width.unwrap_or(0);
+            running_widths.push(total_width);
+            Ok(())
+        });
+        let mut char_index = 0;
+        let breaks = linebreaks(&text)
+            .map(|(byte_index, opportunity)| {
+                while char_index < chars.len() && chars[char_index].0 < byte_index {
+                    char_index += 1;
+                }
+                (char_index, opportunity == BreakOpportunity::Mandatory)
+            })
+            .collect();
+        MeasuredText {
+            text,
+            chars,
+            running_widths,
+            breaks,
+        }
+    }
+
+    /// Byte offset of the character at `index` (or the length of the text).
+    fn byte_offset(&self, index: usize) -> usize {
+        self.chars
+            .get(index)
+            .map_or(self.text.len(), |(offset, _, _)| *offset)
+    }
+
+    /// Total width of the characters between two character indices.
+    fn width_between(&self, start: usize, end: usize) -> usize {
+        self.running_widths[end] - self.running_widths[start]
+    }
+}
+
+/// Line of a [`WrappedValue`], measured by the [`Width`] of the whole value.
+///
+/// **Required features:** `std`
+#[derive(Debug, Clone)]
+pub struct WrappedLine {
+    text: Rc<MeasuredText>,
+    start: usize,
+    end: usize,
+}
+
+impl WrappedLine {
+    /// Text of the line.
+    pub fn as_str(&self) -> &'_ str {
+        &self.text.text[self.text.byte_offset(self.start)..self.text.byte_offset(self.end)]
+    }
+}
+
+impl Display for WrappedLine {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(formatter, "{}", self.as_str())
+    }
+}
+
+impl Width for WrappedLine {
+    fn width(&self) -> usize {
+        self.text.width_between(self.start, self.end)
+    }
+
+    fn for_each_char_width(&self, callback: CharWidthCallback<'_>) -> Result<(), Error> {
+        self.text.chars[self.start..self.end]
+            .iter()
+            .try_for_each(|(_, character, width)| callback(*character, *width))
+    }
+}
+
+/// Iterator created by calling [`lines`](WrappedValue::lines) on [`WrappedValue`].
+///
+/// **Required features:** `std`
+#[derive(Debug, Clone)]
+pub struct WrappedLines<PadBlock = char, Pad = Alignment>
+where
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy,
+{
+    text: Rc<MeasuredText>,
+    /// Character index where the next line starts.
+    position: usize,
+    /// Index of the first break opportunity after `position`.
+    next_break: usize,
+    finished: bool,
+    pad_block: PadBlock,
+    total_width: usize,
+    pad: Pad,
+}
+
+impl<PadBlock, Pad> WrappedLines<PadBlock, Pad>
+where
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy,
+{
+    /// Find the end of the next line, which starts at `self.position`.
+    ///
+    /// Return the end of the content and the start of the line after (both are character indices).
+    fn next_line_end(&mut self) -> (usize, usize) {
+        let MeasuredText { chars, breaks, .. } = &*self.text;
+        let position = self.position;
+        while self.next_break < breaks.len() && breaks[self.next_break].0 <= position {
+            self.next_break += 1;
+        }
+        let mut fitting_end = None;
+        for &(index, mandatory) in &breaks[self.next_break..] {
+            let mut content_end = index;
+            while content_end > position && chars[content_end - 1].1.is_whitespace() {
+                content_end -= 1;
+            }
+            if self.text.width_between(position, content_end) > self.total_width {
+                break;
+            }
+            fitting_end = Some((content_end, index));
+            if mandatory {
+                break;
+            }
+        }
+        if let Some(fitting_end) = fitting_end {
+            return fitting_end;
+        }
+        let mut end = position;
+        while end < chars.len()
+            && (end == position || self.text.width_between(position, end + 1) <= self.total_width)
+        {
+            end += 1;
+        }
+        (end, end)
+    }
+}
+
+impl<PadBlock, Pad> Iterator for WrappedLines<PadBlock, Pad>
+where
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy,
+{
+    type Item = PaddedValue<WrappedLine, PadBlock, TruncateEnd, Pad>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let (content_end, next_start) = self.next_line_end();
+        let value = WrappedLine {
+            text: Rc::clone(&self.text),
+            start: self.position,
+            end: content_end,
+        };
+        self.position = next_start;
+        self.finished = next_start >= self.text.chars.len();
+        Some(PaddedValue {
+            value,
+            pad_block: self.pad_block,
+            total_width: self.total_width,
+            pad: self.pad,
+            handle_excess: TruncateEnd,
+        })
+    }
+}
+
+/// Wrap every value in a collection onto multiple lines of the same width.
+///
+/// **Required features:** `std`
+///
+/// **Key traits:**
+/// * [`IntoIterator`]: Build an iterator of wrapped values.
+///
+/// **Example:**
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use zero_copy_pads::{WrappedColumn, AlignLeft};
+/// let values = [
+///     "Rust is fast",
+///     "C",
+///     "JavaScript runs everywhere",
+/// ];
+/// let wrapped_column = WrappedColumn {
+///     values: values.iter(),
+///     pad_block: '.',
+///     total_width: 10,
+///     pad: AlignLeft,
+/// };
+/// let actual: Vec<_> = wrapped_column
+///     .into_iter()
+///     .map(|x| (x.line_count(), x.to_string()))
+///     .collect();
+/// let expected = [
+///     (2, "Rust is...\nfast......".to_string()),
+///     (1, "C.........".to_string()),
+///     (3, "JavaScript\nruns......\neverywhere".to_string()),
+/// ];
+/// assert_eq!(actual, expected);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct WrappedColumn<ValueIter, PadBlock = char, Pad = Alignment>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy,
+{
+    /// Values to be wrapped.
+    pub values: ValueIter,
+    /// Block of the pad (see [`PadBlock`](crate::PadBlock)).
+    pub pad_block: PadBlock,
+    /// Maximum width of every line, which is also the width every line is padded to.
+    pub total_width: usize,
+    /// How to pad every line.
+    pub pad: Pad,
+}
+
+impl<ValueIter, PadBlock, Pad> IntoIterator for WrappedColumn<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy,
+{
+    type Item = WrappedValue<ValueIter::Item, PadBlock, Pad>;
+    type IntoIter = WrappedColumnIter<ValueIter, PadBlock, Pad>;
+    fn into_iter(self) -> Self::IntoIter {
+        WrappedColumnIter(self)
+    }
+}
+
+/// Iterator created by calling [`into_iter`](IntoIterator::into_iter) on [`WrappedColumn`].
+///
+/// **Required features:** `std`
+#[derive(Debug, Clone, Copy)]
+pub struct WrappedColumnIter<ValueIter, PadBlock = char, Pad = Alignment>(
+    WrappedColumn<ValueIter, PadBlock, Pad>,
+)
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy;
+
+impl<ValueIter, PadBlock, Pad> WrappedColumnIter<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy,
+{
+    /// Pad block that was used in the construction of [`WrappedColumn`].
+    pub fn pad_block(&self) -> PadBlock {
+        self.0.pad_block
+    }
+
+    /// Padding method that was used in the construction of [`WrappedColumn`].
+    pub fn pad(&self) -> Pad {
+        self.0.pad
+    }
+
+    /// Width of every line that was used in the construction of [`WrappedColumn`].
+    pub fn total_width(&self) -> usize {
+        self.0.total_width
+    }
+}
+
+impl<ValueIter, PadBlock, Pad> Iterator for WrappedColumnIter<ValueIter, PadBlock, Pad>
+where
+    ValueIter: Iterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy,
+{
+    type Item = WrappedValue<ValueIter::Item, PadBlock, Pad>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let WrappedColumn {
+            values,
+            pad_block,
+            total_width,
+            pad,
+        } = &mut self.0;
+        values.next().map(|value| WrappedValue {
+            value,
+            pad_block: *pad_block,
+            total_width: *total_width,
+            pad: *pad,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.values.size_hint()
+    }
+}
+
+impl<ValueIter, PadBlock, Pad> ExactSizeIterator for WrappedColumnIter<ValueIter, PadBlock, Pad>
+where
+    ValueIter: ExactSizeIterator,
+    ValueIter::Item: Width,
+    PadBlock: crate::PadBlock + Copy,
+    Pad: crate::Pad<WrappedLine, PadBlock> + Copy,
+{
+    fn len(&self) -> usize {
+        self.0.values.len()
+    }
+}
@@ -0,0 +1,62 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{Alignment, ParseAlignmentError};
+
+#[test]
+fn round_trip() {
+    for alignment in Alignment::ALL.iter().copied() {
+        let name = alignment.to_string();
+        assert_eq!(name, alignment.name());
+        assert_eq!(name.parse::<Alignment>(), Ok(alignment));
+    }
+}
+
+#[test]
+fn names() {
+    let actual: Vec<_> = Alignment::ALL.iter().map(|x| x.name()).collect();
+    let expected = [
+        "left",
+        "right",
+        "center-left",
+        "center-right",
+        "justify-bias-left",
+        "justify-bias-right",
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn unknown_name() {
+    assert_eq!("middle".parse::<Alignment>(), Err(ParseAlignmentError));
+    assert_eq!("Left".parse::<Alignment>(), Err(ParseAlignmentError));
+    assert_eq!(ParseAlignmentError.to_string(), "unknown alignment");
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use pretty_assertions::assert_eq;
+    use zero_copy_pads::Alignment;
+
+    #[test]
+    fn serialize() {
+        let actual = serde_json::to_string(&[Alignment::Left, Alignment::CenterRight]).unwrap();
+        assert_eq!(actual, r#"["left","center-right"]"#);
+    }
+
+    #[test]
+    fn deserialize() {
+        let actual: Vec<Alignment> =
+            serde_json::from_str(r#"["justify-bias-left","right"]"#).unwrap();
+        assert_eq!(actual, [Alignment::JustifyBiasLeft, Alignment::Right]);
+    }
+
+    #[test]
+    fn deserialize_unknown_name() {
+        let error = serde_json::from_str::<Alignment>(r#""middle""#)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            r#"invalid value: string "middle", expected one of "left", "right", "center-left", "center-right", "justify-bias-left", "justify-bias-right" at line 1 column 8"#,
+        );
+    }
+}
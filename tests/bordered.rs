@@ -0,0 +1,217 @@
+#![cfg(feature = "std")]
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{
+    Alignment, Border, BorderRule, BorderedTable, BorderedTableBuilder, ParseTableStyleError,
+    TableStyle, Width,
+};
+
+fn rows() -> Vec<Vec<&'static str>> {
+    vec![
+        vec!["id", "name"],
+        vec!["1", "\u{4F60}\u{597D}"],
+        vec!["22", "x"],
+    ]
+}
+
+fn render(border: Border, header: bool) -> Vec<String> {
+    let rows = rows();
+    BorderedTable {
+        rows: rows.iter(),
+        pad_block: ' ',
+        pads: vec![Alignment::Right],
+        border,
+        header,
+    }
+    .into_iter()
+    .map(|x| x.to_string())
+    .collect()
+}
+
+#[test]
+fn ascii() {
+    let expected = [
+        "+----+------+",
+        "| id | name |",
+        "+----+------+",
+        "|  1 | \u{4F60}\u{597D} |",
+        "| 22 | x    |",
+        "+----+------+",
+    ];
+    assert_eq!(render(TableStyle::Ascii.border(), true), expected);
+}
+
+#[test]
+fn without_header() {
+    let expected = [
+        "┏━━━━┳━━━━━━┓",
+        "┃ id ┃ name ┃",
+        "┃  1 ┃ \u{4F60}\u{597D} ┃",
+        "┃ 22 ┃ x    ┃",
+        "┗━━━━┻━━━━━━┛",
+    ];
+    assert_eq!(render(TableStyle::Heavy.border(), false), expected);
+}
+
+#[test]
+fn double() {
+    let expected = [
+        "╔════╦══════╗",
+        "║ id ║ name ║",
+        "╠════╬══════╣",
+        "║  1 ║ \u{4F60}\u{597D} ║",
+        "║ 22 ║ x    ║",
+        "╚════╩══════╝",
+    ];
+    assert_eq!(render(TableStyle::Double.border(), true), expected);
+}
+
+#[test]
+fn rounded() {
+    let actual = render(TableStyle::Rounded.border(), true);
+    assert_eq!(actual[0], "╭────┬──────╮");
+    assert_eq!(actual[2], "├────┼──────┤");
+    assert_eq!(actual[5], "╰────┴──────╯");
+}
+
+#[test]
+fn borderless() {
+    let expected = ["id name", " 1 \u{4F60}\u{597D}", "22 x   "];
+    assert_eq!(render(TableStyle::Borderless.border(), true), expected);
+}
+
+#[test]
+fn markdown() {
+    let expected = [
+        "| id | name |",
+        "|----|------|",
+        "|  1 | \u{4F60}\u{597D} |",
+        "| 22 | x    |",
+    ];
+    assert_eq!(render(TableStyle::Markdown.border(), true), expected);
+}
+
+#[test]
+fn custom_border() {
+    let rule = BorderRule {
+        left: "",
+        fill: '=',
+        junction: "=",
+        right: "",
+    };
+    let border = Border {
+        top: None,
+        header: Some(rule),
+        bottom: Some(rule),
+        left: "",
+        separator: ":",
+        right: "",
+        padding: 0,
+    };
+    let expected = [
+        "id:name",
+        "=======",
+        " 1:\u{4F60}\u{597D}",
+        "22:x   ",
+        "=======",
+    ];
+    assert_eq!(render(border, true), expected);
+}
+
+#[test]
+fn ragged_rows() {
+    let rows = [vec!["a", "b"], vec!["c"]];
+    let actual: Vec<_> = BorderedTable {
+        rows: rows.iter(),
+        pad_block: '.',
+        pads: Vec::<Alignment>::new(),
+        border: TableStyle::Ascii.border(),
+        header: false,
+    }
+    .into_iter()
+    .map(|x| x.to_string())
+    .collect();
+    assert_eq!(actual, ["+---+---+", "| a | b |", "| c | . |", "+---+---+"]);
+}
+
+#[test]
+fn empty_table() {
+    let rows: Vec<Vec<&str>> = Vec::new();
+    let iter = BorderedTable {
+        rows: rows.iter(),
+        pad_block: ' ',
+        pads: vec![Alignment::Left],
+        border: TableStyle::Light.border(),
+        header: true,
+    }
+    .into_iter();
+    assert_eq!(iter.len(), 2);
+    let actual: Vec<_> = iter.map(|x| x.to_string()).collect();
+    assert_eq!(actual, ["┌┐", "└┘"]);
+}
+
+#[test]
+fn lines_and_widths() {
+    let rows = rows();
+    let mut iter = BorderedTable {
+        rows: rows.iter(),
+        pad_block: ' ',
+        pads: Vec::<Alignment>::new(),
+        border: TableStyle::Light.border(),
+        header: true,
+    }
+    .into_iter();
+    assert_eq!(iter.column_widths(), &[2, 4]);
+    assert_eq!(iter.total_width(), 13);
+    assert_eq!(iter.border(), TableStyle::Light.border());
+    let mut actual = Vec::new();
+    while let Some(line) = iter.next() {
+        assert_eq!(line.width(), 13);
+        assert_eq!(line.width(), line.to_string().width());
+        let kind = match (line.rule(), line.row()) {
+            (Some(_), None) => "rule",
+            (None, Some(row)) => {
+                assert_eq!(row.cells().len(), 2);
+                "row"
+            }
+            _ => unreachable!(),
+        };
+        actual.push((kind, iter.len()));
+    }
+    let expected = [
+        ("rule", 5),
+        ("row", 4),
+        ("rule", 3),
+        ("row", 2),
+        ("row", 1),
+        ("rule", 0),
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn style_names() {
+    for style in TableStyle::ALL.iter().copied() {
+        assert_eq!(style.name().parse(), Ok(style));
+        assert_eq!(style.to_string(), style.name());
+        assert_eq!(Border::from(style), style.border());
+    }
+    assert_eq!("Light".parse::<TableStyle>(), Err(ParseTableStyleError));
+    assert_eq!(ParseTableStyleError.to_string(), "unknown table style");
+}
+
+#[test]
+fn builder() {
+    let rows = rows();
+    let actual: Vec<_> = BorderedTableBuilder::default()
+        .rows(rows.iter())
+        .pad_block(' ')
+        .pads(vec![Alignment::Left])
+        .border("markdown".parse::<TableStyle>().unwrap().border())
+        .header(true)
+        .build()
+        .unwrap()
+        .into_iter()
+        .map(|x| x.to_string())
+        .collect();
+    assert_eq!(actual[2], "| 1  | \u{4F60}\u{597D} |");
+}
@@ -0,0 +1,79 @@
+use fmt_iter::FmtIter;
+use pipe_trait::Pipe;
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{AlignLeft, AlignRight, Alignment, BufferedColumnIter, CapacityError, Width};
+
+#[test]
+fn inline_buffer() {
+    let mut iter = BufferedColumnIter::<_, [_; 4], _, _>::new_inline('-', AlignLeft);
+    assert_eq!(iter.capacity(), 4);
+    iter.try_extend(["", "a", "bc", "def"].iter().copied())
+        .unwrap();
+    assert!(iter.is_full());
+    let mut actual = Vec::new();
+    while let Some(value) = iter.next() {
+        actual.push((iter.len(), value.to_string()));
+    }
+    let expected = [
+        (3, "---".to_string()),
+        (2, "a--".to_string()),
+        (1, "bc-".to_string()),
+        (0, "def".to_string()),
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn capacity_exceeded() {
+    let mut buffer = [None, None, None];
+    let mut iter = BufferedColumnIter::new(&mut buffer[..], '-', AlignRight);
+    let error = iter.try_extend(vec!["a", "bc", "def", "ghij", "k"]);
+    assert_eq!(
+        error,
+        Err(CapacityError {
+            value: "ghij",
+            capacity: 3,
+        }),
+    );
+    assert_eq!(
+        error.unwrap_err().to_string(),
+        "column buffer is full (capacity: 3)",
+    );
+    assert_eq!(iter.total_width(), 3);
+    let actual: Vec<_> = iter.map(|x| x.to_string()).collect();
+    assert_eq!(actual, ["--a", "-bc", "def"]);
+}
+
+#[test]
+fn reuse_slots_after_pop() {
+    let mut iter = BufferedColumnIter::<_, [_; 2]>::new_inline('.', Alignment::Left);
+    iter.try_push_back("a").unwrap();
+    iter.try_push_back("b").unwrap();
+    assert_eq!(iter.next().map(|x| x.to_string()), Some("a".to_string()));
+    iter.try_push_back("cde").unwrap();
+    assert!(iter.try_push_back("f").is_err());
+    let actual: Vec<_> = iter.map(|x| x.to_string()).collect();
+    assert_eq!(actual, ["b..", "cde"]);
+}
+
+#[test]
+fn zero_capacity() {
+    let mut iter = BufferedColumnIter::<&str, [_; 0], _, _>::new_inline(' ', AlignLeft);
+    assert!(iter.is_full());
+    assert_eq!(
+        iter.try_push_back("a"),
+        Err(CapacityError {
+            value: "a",
+            capacity: 0,
+        }),
+    );
+    assert_eq!(iter.next().map(|x| x.to_string()), None);
+}
+
+#[test]
+fn fmt_iter_width() {
+    let mut iter = BufferedColumnIter::<_, [_; 3], _, _>::new_inline(' ', AlignLeft);
+    iter.try_extend(vec!["a", "bcd", "ef"]).unwrap();
+    let actual = iter.pipe(FmtIter::from).width();
+    assert_eq!(actual, 9);
+}
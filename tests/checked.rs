@@ -0,0 +1,92 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{
+    AlignLeft, CheckedColumn, ColumnExcessError, ExcessError, IgnoreExcess, PaddedValue,
+    RenderError,
+};
+
+#[test]
+fn try_render_without_excess() {
+    let padded_value = PaddedValue {
+        handle_excess: IgnoreExcess,
+        value: "abc",
+        total_width: 5,
+        pad_block: '-',
+        pad: AlignLeft,
+    };
+    let mut output = String::new();
+    assert_eq!(padded_value.try_render(&mut output), Ok(()));
+    assert_eq!(output, "abc--");
+}
+
+#[test]
+fn try_render_with_excess() {
+    let padded_value = PaddedValue {
+        handle_excess: IgnoreExcess,
+        value: "abcdefghi",
+        total_width: 5,
+        pad_block: '-',
+        pad: AlignLeft,
+    };
+    let mut output = String::new();
+    assert_eq!(
+        padded_value.try_render(&mut output),
+        Err(RenderError::Excess(ExcessError {
+            value_width: 9,
+            total_width: 5,
+        })),
+    );
+    assert_eq!(output, "");
+}
+
+#[test]
+fn try_render_with_failing_writer() {
+    struct FailingWriter;
+    impl std::fmt::Write for FailingWriter {
+        fn write_str(&mut self, _: &str) -> std::fmt::Result {
+            Err(std::fmt::Error)
+        }
+    }
+    let padded_value = PaddedValue {
+        handle_excess: IgnoreExcess,
+        value: "abc",
+        total_width: 5,
+        pad_block: '-',
+        pad: AlignLeft,
+    };
+    assert_eq!(
+        padded_value.try_render(&mut FailingWriter),
+        Err(RenderError::Fmt(std::fmt::Error)),
+    );
+}
+
+#[test]
+fn checked_column() {
+    let values = ["abc", "abcdefghi", "", "abcdef"];
+    let actual: Vec<_> = CheckedColumn {
+        values: values.iter(),
+        pad_block: '-',
+        total_width: 5,
+        pad: AlignLeft,
+    }
+    .into_iter()
+    .map(|x| x.map(|x| x.to_string()))
+    .collect();
+    let excess = |index, value_width| ColumnExcessError {
+        index,
+        excess: ExcessError {
+            value_width,
+            total_width: 5,
+        },
+    };
+    let expected = [
+        Ok("abc--".to_string()),
+        Err(excess(1, 9)),
+        Ok("-----".to_string()),
+        Err(excess(3, 6)),
+    ];
+    assert_eq!(actual, expected);
+    assert_eq!(
+        excess(3, 6).to_string(),
+        "row 3: value's width (6) is greater than total_width (5)",
+    );
+}
@@ -0,0 +1,171 @@
+#![cfg(feature = "std")]
+use fmt_iter::FmtIter;
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{
+    AlignLeft, AlignRight, Alignment, ConstrainedColumn, ConstrainedColumnBuilder, IgnoreExcess,
+    PaddedColumn, PaddedColumnIter, TruncateEnd, Width, WidthConstraint,
+};
+
+const VALUES: &[&str] = &["Rust", "C", "C++", "C#", "JavaScript", "Go"];
+
+fn render<HandleExcess>(
+    width_constraint: WidthConstraint,
+    handle_excess: HandleExcess,
+) -> Vec<String>
+where
+    HandleExcess: zero_copy_pads::ExcessHandler<&'static &'static str> + Copy,
+{
+    ConstrainedColumn {
+        values: VALUES.iter(),
+        pad_block: '-',
+        pad: AlignLeft,
+        width_constraint,
+        handle_excess,
+    }
+    .into_iter()
+    .map(|x| x.to_string())
+    .collect()
+}
+
+#[test]
+fn unconstrained() {
+    let actual = render(WidthConstraint::default(), TruncateEnd);
+    let expected: Vec<_> = VALUES.iter().map(|x| format!("{:-<10}", x)).collect();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn min_width() {
+    let constraint = WidthConstraint {
+        min_width: 12,
+        ..WidthConstraint::default()
+    };
+    let actual = render(constraint, TruncateEnd);
+    let expected: Vec<_> = VALUES.iter().map(|x| format!("{:-<12}", x)).collect();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn max_width() {
+    let constraint = WidthConstraint {
+        max_width: Some(3),
+        ..WidthConstraint::default()
+    };
+    let actual = render(constraint, TruncateEnd);
+    assert_eq!(actual, ["Rus", "C--", "C++", "C#-", "Jav", "Go-"]);
+}
+
+#[test]
+fn max_width_takes_precedence() {
+    let constraint = WidthConstraint {
+        min_width: 8,
+        max_width: Some(2),
+        fixed_width: None,
+    };
+    assert_eq!(constraint.apply(1), 2);
+    let actual = render(constraint, IgnoreExcess);
+    assert_eq!(actual, ["Rust", "C-", "C++", "C#", "JavaScript", "Go"]);
+}
+
+#[test]
+fn fixed_width() {
+    let constraint = WidthConstraint {
+        min_width: 100,
+        max_width: Some(1),
+        fixed_width: Some(5),
+    };
+    assert_eq!(constraint, {
+        let mut fixed = WidthConstraint::fixed(5);
+        fixed.min_width = 100;
+        fixed.max_width = Some(1);
+        fixed
+    });
+    let actual = render(constraint, TruncateEnd);
+    assert_eq!(
+        actual,
+        ["Rust-", "C----", "C++--", "C#---", "JavaS", "Go---"]
+    );
+}
+
+#[test]
+fn iter_accessors() {
+    let iter = PaddedColumn {
+        values: VALUES.iter(),
+        pad_block: ' ',
+        pad: AlignRight,
+    }
+    .into_iter();
+    assert_eq!(iter.total_width(), 10);
+    assert_eq!(iter.width_constraint(), WidthConstraint::default());
+    let iter = iter
+        .with_width_constraint(WidthConstraint::fixed(4))
+        .with_excess_handler(TruncateEnd);
+    assert_eq!(iter.total_width(), 4);
+    assert_eq!(iter.width_constraint(), WidthConstraint::fixed(4));
+    assert_eq!(iter.handle_excess(), TruncateEnd);
+    assert_eq!(FmtIter::from(iter.clone()).width(), 24);
+    let actual: Vec<_> = iter.map(|x| x.to_string()).collect();
+    assert_eq!(actual, ["Rust", "   C", " C++", "  C#", "Java", "  Go"]);
+}
+
+#[test]
+fn iter_constraint_without_handler() {
+    let mut iter =
+        PaddedColumnIter::new('.', AlignLeft).with_width_constraint(WidthConstraint::fixed(5));
+    iter.extend(VALUES.iter());
+    assert_eq!(iter.handle_excess(), TruncateEnd);
+    let actual: Vec<_> = iter.map(|x| x.to_string()).collect();
+    assert_eq!(
+        actual,
+        ["Rust.", "C....", "C++..", "C#...", "JavaS", "Go..."]
+    );
+}
+
+#[test]
+fn wide_characters() {
+    let values = ["\u{4F60}\u{597D}\u{4E16}\u{754C}", "ab"];
+    let actual: Vec<_> = ConstrainedColumn {
+        values: values.iter(),
+        pad_block: ' ',
+        pad: AlignLeft,
+        width_constraint: WidthConstraint {
+            max_width: Some(5),
+            ..WidthConstraint::default()
+        },
+        handle_excess: TruncateEnd,
+    }
+    .into_iter()
+    .map(|x| x.to_string())
+    .collect();
+    assert_eq!(actual, ["\u{4F60}\u{597D} ", "ab   "]);
+}
+
+#[test]
+fn builder() {
+    let actual: Vec<_> = ConstrainedColumnBuilder::default()
+        .values(VALUES.iter())
+        .pad_block(' ')
+        .pad(AlignRight)
+        .width_constraint(WidthConstraint::fixed(6))
+        .handle_excess(TruncateEnd)
+        .build()
+        .unwrap()
+        .into_iter()
+        .map(|x| x.to_string())
+        .collect();
+    assert_eq!(actual[0], "  Rust");
+    assert_eq!(actual[4], "JavaSc");
+}
+
+#[test]
+fn default_handler_truncates() {
+    let column: ConstrainedColumn<_> = ConstrainedColumn {
+        values: VALUES.iter(),
+        pad_block: ' ',
+        pad: Alignment::Left,
+        width_constraint: WidthConstraint::fixed(3),
+        handle_excess: Default::default(),
+    };
+    let actual: Vec<_> = column.into_iter().map(|x| x.to_string()).collect();
+    assert_eq!(actual, ["Rus", "C  ", "C++", "C# ", "Jav", "Go "]);
+}
@@ -0,0 +1,165 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{AlignDecimal, PaddedValue, PanicOnExcess};
+
+#[test]
+fn align_decimal_without_fraction() {
+    let padded_value = PaddedValue {
+        value: "42",
+        pad_block: '-',
+        total_width: 7,
+        pad: AlignDecimal {
+            separator: '.',
+            integer_width: 4,
+        },
+        handle_excess: PanicOnExcess,
+    };
+    assert_eq!(padded_value.to_string(), "--42---");
+}
+
+#[test]
+fn align_decimal_integer_wider_than_expected() {
+    let padded_value = PaddedValue {
+        value: "12345.6",
+        pad_block: '-',
+        total_width: 9,
+        pad: AlignDecimal {
+            separator: '.',
+            integer_width: 3,
+        },
+        handle_excess: PanicOnExcess,
+    };
+    assert_eq!(padded_value.to_string(), "12345.6--");
+}
+
+#[cfg(feature = "std")]
+mod column {
+    use pretty_assertions::assert_eq;
+    use zero_copy_pads::{AlignDecimal, DecimalColumn, DecimalColumnBuilder, Width};
+
+    #[test]
+    fn dot_separator() {
+        let values = ["3.14", "120.5", "7", "0.125", "1000"];
+        let iter = DecimalColumn {
+            values: values.iter(),
+            pad_block: '_',
+            separator: '.',
+        }
+        .into_iter();
+        assert_eq!(iter.total_width(), 8);
+        assert_eq!(
+            iter.pad(),
+            AlignDecimal {
+                separator: '.',
+                integer_width: 4,
+            },
+        );
+        let actual: Vec<_> = iter.map(|x| x.to_string()).collect();
+        let expected = ["___3.14_", "_120.5__", "___7____", "___0.125", "1000____"];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn comma_separator() {
+        let values = ["3,14", "120,5", "1.000"];
+        let actual: Vec<_> = DecimalColumnBuilder::default()
+            .values(values.iter())
+            .pad_block(' ')
+            .separator(',')
+            .build()
+            .unwrap()
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect();
+        let expected = ["    3,14", "  120,5 ", "1.000   "];
+        assert_eq!(actual, expected);
+    }
+
+    /// Value whose width is twice the number of its characters.
+    struct DoubleWidth(&'static str);
+
+    impl core::fmt::Display for DoubleWidth {
+        fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(formatter, "{}", self.0)
+        }
+    }
+
+    impl Width for DoubleWidth {
+        fn width(&self) -> usize {
+            self.0.chars().count() * 2
+        }
+
+        fn for_each_char_width(
+            &self,
+            callback: &mut dyn FnMut(char, Option<usize>) -> core::fmt::Result,
+        ) -> core::fmt::Result {
+            self.0
+                .chars()
+                .try_for_each(|character| callback(character, Some(2)))
+        }
+    }
+
+    #[test]
+    fn measured_by_value_width() {
+        let values = [DoubleWidth("1.5"), DoubleWidth("10.25"), DoubleWidth("7")];
+        let iter = DecimalColumn {
+            values: values.iter(),
+            pad_block: '_',
+            separator: '.',
+        }
+        .into_iter();
+        assert_eq!(iter.total_width(), 10);
+        let actual: Vec<_> = iter.map(|x| x.to_string()).collect();
+        assert_eq!(actual, ["__1.5__", "10.25", "__7______"]);
+        // Pad blocks take 1 column, characters of `DoubleWidth` take 2 columns.
+        let separator_column = |text: &str| -> usize {
+            text.chars()
+                .take_while(|character| *character != '.')
+                .map(|character| if character == '_' { 1 } else { 2 })
+                .sum()
+        };
+        assert_eq!(separator_column(&actual[0]), 4);
+        assert_eq!(separator_column(&actual[1]), 4);
+    }
+
+    /// Value that only implements the required [`Width::width`].
+    struct WidthOnly(&'static str);
+
+    impl core::fmt::Display for WidthOnly {
+        fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(formatter, "{}", self.0)
+        }
+    }
+
+    impl Width for WidthOnly {
+        fn width(&self) -> usize {
+            self.0.chars().count() * 2
+        }
+    }
+
+    #[test]
+    fn total_width_covers_value_width() {
+        let values = [WidthOnly("1.5"), WidthOnly("10")];
+        let iter = DecimalColumn {
+            values: values.iter(),
+            pad_block: '_',
+            separator: '.',
+        }
+        .into_iter();
+        assert_eq!(iter.total_width(), 8);
+        let actual: Vec<_> = iter.map(|x| x.to_string()).collect();
+        assert_eq!(actual, ["__1.5", "10____"]);
+    }
+
+    #[test]
+    fn empty() {
+        let values: [&str; 0] = [];
+        let iter = DecimalColumn {
+            values: values.iter(),
+            pad_block: ' ',
+            separator: '.',
+        }
+        .into_iter();
+        assert_eq!(iter.total_width(), 0);
+        assert_eq!(iter.count(), 0);
+    }
+}
@@ -0,0 +1,83 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{
+    AlignRight,
+    EllipsisPosition::{self, End, Middle, Start},
+    Ellipsize, PaddedValue,
+};
+
+fn create(marker: &'static str, position: EllipsisPosition, value: &str, width: usize) -> String {
+    PaddedValue {
+        handle_excess: Ellipsize { marker, position },
+        value,
+        total_width: width,
+        pad_block: '-',
+        pad: AlignRight,
+    }
+    .to_string()
+}
+
+#[test]
+fn without_excess() {
+    assert_eq!(create("…", Start, "abcdef", 9), "---abcdef");
+    assert_eq!(create("…", Middle, "abcdef", 9), "---abcdef");
+    assert_eq!(create("…", End, "abcdef", 9), "---abcdef");
+}
+
+#[test]
+fn with_excess() {
+    assert_eq!(create("…", Start, "abcdefghi", 6), "…efghi");
+    assert_eq!(create("…", Middle, "abcdefghi", 6), "abc…hi");
+    assert_eq!(create("…", End, "abcdefghi", 6), "abcde…");
+    assert_eq!(create("...", Start, "abcdefghi", 6), "...ghi");
+    assert_eq!(create("...", Middle, "abcdefghi", 6), "ab...i");
+    assert_eq!(create("...", End, "abcdefghi", 6), "abc...");
+}
+
+#[test]
+fn with_wide_characters() {
+    assert_eq!(create("…", Start, "中文字符", 6), "…-字符");
+    assert_eq!(create("…", Middle, "中文字符", 6), "中-…符");
+    assert_eq!(create("…", End, "中文字符", 6), "中文-…");
+}
+
+#[test]
+fn marker_wider_than_total_width() {
+    assert_eq!(create("...", Start, "abcdefghi", 2), "hi");
+    assert_eq!(create("...", Middle, "abcdefghi", 2), "ai");
+    assert_eq!(create("...", End, "abcdefghi", 2), "ab");
+}
+
+#[test]
+fn default() {
+    let padded_value = PaddedValue {
+        handle_excess: Ellipsize::default(),
+        value: "abcdefghi",
+        total_width: 6,
+        pad_block: ' ',
+        pad: AlignRight,
+    };
+    assert_eq!(padded_value.to_string(), "abcde…");
+}
+
+#[test]
+fn measured_by_width_of_value_and_marker() {
+    use zero_copy_pads::CharCount;
+    let create = |marker: CharCount<&'static str>, position, value, width| {
+        PaddedValue {
+            handle_excess: Ellipsize { marker, position },
+            value: CharCount::from(value),
+            total_width: width,
+            pad_block: '-',
+            pad: AlignRight,
+        }
+        .to_string()
+    };
+    let marker = CharCount::from("\u{22EF}\u{22EF}");
+    assert_eq!(create(marker, End, "中文字符", 3), "中\u{22EF}\u{22EF}");
+    assert_eq!(create(marker, Start, "中文字符", 3), "\u{22EF}\u{22EF}符");
+    assert_eq!(
+        create(marker, Middle, "中文字符串", 4),
+        "中\u{22EF}\u{22EF}串"
+    );
+    assert_eq!(create(CharCount::from("…"), End, "中文字符", 4), "中文字符");
+}
@@ -1,5 +1,8 @@
 use pretty_assertions::assert_eq;
-use zero_copy_pads::{AlignRight, ErrorOnExcess, IgnoreExcess, PaddedValue, PanicOnExcess};
+use zero_copy_pads::{
+    AlignRight, AnsiUnicodeWidth, CharCount, ErrorOnExcess, IgnoreExcess, Len, PaddedValue,
+    PanicOnExcess, TruncateEnd, TruncateMiddle, TruncateStart,
+};
 
 macro_rules! create {
     ($handle_excess:expr, $value:expr, $total_width:expr) => {
@@ -45,3 +48,101 @@ fn ignore_excess_without_excess() {
 fn ignore_excess_with_excess() {
     assert_eq!(create!(IgnoreExcess, "abcdefghi", 6), "abcdefghi");
 }
+
+#[test]
+fn truncate_start_without_excess() {
+    assert_eq!(create!(TruncateStart, "abcdef", 9), "---abcdef");
+}
+
+#[test]
+fn truncate_start_with_excess() {
+    assert_eq!(create!(TruncateStart, "abcdefghi", 6), "defghi");
+    assert_eq!(create!(TruncateStart, "abcdefghi", 0), "");
+}
+
+#[test]
+fn truncate_start_with_wide_characters() {
+    assert_eq!(create!(TruncateStart, "中文字符", 4), "字符");
+    assert_eq!(create!(TruncateStart, "中文字符", 5), "-字符");
+    assert_eq!(create!(TruncateStart, "abc中文", 5), "c中文");
+    assert_eq!(create!(TruncateStart, "e\u{301}e\u{301}", 1), "e\u{301}");
+}
+
+#[test]
+fn truncate_end_without_excess() {
+    assert_eq!(create!(TruncateEnd, "abcdef", 9), "---abcdef");
+}
+
+#[test]
+fn truncate_end_with_excess() {
+    assert_eq!(create!(TruncateEnd, "abcdefghi", 6), "abcdef");
+    assert_eq!(create!(TruncateEnd, "abcdefghi", 0), "");
+}
+
+#[test]
+fn truncate_end_with_wide_characters() {
+    assert_eq!(create!(TruncateEnd, "中文字符", 4), "中文");
+    assert_eq!(create!(TruncateEnd, "中文字符", 5), "中文-");
+    assert_eq!(create!(TruncateEnd, "中文abc", 5), "中文a");
+    assert_eq!(create!(TruncateEnd, "e\u{301}e\u{301}", 1), "e\u{301}");
+}
+
+#[test]
+fn truncate_end_keeps_ansi_escape_sequences() {
+    let value = AnsiUnicodeWidth::from("\u{1B}[31mabcdef\u{1B}[0m");
+    assert_eq!(create!(TruncateEnd, value, 3), "\u{1B}[31mabc\u{1B}[0m");
+}
+
+#[test]
+fn truncate_by_width_of_value() {
+    assert_eq!(create!(TruncateEnd, CharCount::from("中文字符"), 2), "中文");
+    assert_eq!(
+        create!(TruncateStart, CharCount::from("中文字符"), 3),
+        "文字符"
+    );
+    assert_eq!(
+        create!(TruncateMiddle, CharCount::from("中文字符"), 2),
+        "中符"
+    );
+    assert_eq!(
+        create!(TruncateEnd, Len::from("\u{E9}\u{E9}"), 3),
+        "\u{E9}-"
+    );
+    assert_eq!(create!(TruncateStart, Len::from("ab\u{E9}"), 2), "\u{E9}");
+}
+
+#[cfg(feature = "grapheme")]
+#[test]
+fn truncate_keeps_grapheme_clusters() {
+    use zero_copy_pads::GraphemeWidth;
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let value = format!("{}ab", family);
+    assert_eq!(
+        create!(TruncateEnd, GraphemeWidth::from(value.as_str()), 3),
+        format!("{}a", family),
+    );
+    assert_eq!(
+        create!(TruncateStart, GraphemeWidth::from(value.as_str()), 3),
+        "-ab",
+    );
+}
+
+#[test]
+fn truncate_middle_without_excess() {
+    assert_eq!(create!(TruncateMiddle, "abcdef", 9), "---abcdef");
+}
+
+#[test]
+fn truncate_middle_with_excess() {
+    assert_eq!(create!(TruncateMiddle, "abcdefghi", 6), "abcghi");
+    assert_eq!(create!(TruncateMiddle, "abcdefghi", 5), "abchi");
+    assert_eq!(create!(TruncateMiddle, "abcdefghi", 1), "a");
+    assert_eq!(create!(TruncateMiddle, "abcdefghi", 0), "");
+}
+
+#[test]
+fn truncate_middle_with_wide_characters() {
+    assert_eq!(create!(TruncateMiddle, "中文字符", 4), "中符");
+    assert_eq!(create!(TruncateMiddle, "中文字符", 5), "中-符");
+    assert_eq!(create!(TruncateMiddle, "中文字符", 6), "中--符");
+}
@@ -0,0 +1,77 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{
+    IgnoreExcess, JustifyBetween, PadBlockWithFallback, PaddedValue, Pair, ShrinkLeft, Width,
+};
+
+#[test]
+fn pair_width() {
+    let pair = Pair {
+        left: "abc",
+        right: "\u{4E2D}",
+    };
+    assert_eq!(pair.width(), 5);
+    assert_eq!(pair.to_string(), "abc\u{4E2D}");
+}
+
+#[test]
+fn exact_fit() {
+    let padded_value = PaddedValue {
+        value: Pair {
+            left: "abc",
+            right: "def",
+        },
+        pad_block: '.',
+        total_width: 6,
+        pad: JustifyBetween,
+        handle_excess: IgnoreExcess,
+    };
+    assert_eq!(padded_value.to_string(), "abcdef");
+}
+
+#[test]
+fn leader_pattern() {
+    let padded_value = PaddedValue {
+        value: Pair {
+            left: "Introduction",
+            right: "3",
+        },
+        pad_block: PadBlockWithFallback {
+            block: " .",
+            fallback: ' ',
+        },
+        total_width: 20,
+        pad: JustifyBetween,
+        handle_excess: IgnoreExcess,
+    };
+    assert_eq!(padded_value.to_string(), "Introduction . . . 3");
+}
+
+#[test]
+fn shrink_left() {
+    let padded_value = PaddedValue {
+        value: Pair {
+            left: "Introduction",
+            right: "123",
+        },
+        pad_block: '.',
+        total_width: 10,
+        pad: JustifyBetween,
+        handle_excess: ShrinkLeft::default(),
+    };
+    assert_eq!(padded_value.to_string(), "Introd…123");
+}
+
+#[test]
+fn shrink_left_with_excessive_right() {
+    let padded_value = PaddedValue {
+        value: Pair {
+            left: "Introduction",
+            right: "123456",
+        },
+        pad_block: '.',
+        total_width: 4,
+        pad: JustifyBetween,
+        handle_excess: ShrinkLeft::default(),
+    };
+    assert_eq!(padded_value.to_string(), "Intr");
+}
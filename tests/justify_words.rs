@@ -0,0 +1,120 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{
+    AnsiUnicodeWidth, CharWidthCallback, JustifyBiasLeft, JustifyBiasRight, Pad, PaddedValue,
+    PanicOnExcess, Width,
+};
+
+fn justify<Justify: Pad<&'static str, char>>(
+    pad: Justify,
+    value: &'static str,
+    total_width: usize,
+) -> String {
+    PaddedValue {
+        pad,
+        value,
+        pad_block: ' ',
+        total_width,
+        handle_excess: PanicOnExcess,
+    }
+    .to_string()
+}
+
+#[test]
+fn even_distribution() {
+    let expected = "one   two   three";
+    assert_eq!(justify(JustifyBiasLeft, "one two three", 17), expected);
+    assert_eq!(justify(JustifyBiasRight, "one two three", 17), expected);
+}
+
+#[test]
+fn remainder_distribution() {
+    assert_eq!(justify(JustifyBiasLeft, "a b c d", 10), "a  b  c  d");
+    assert_eq!(justify(JustifyBiasLeft, "a b c d", 9), "a  b  c d");
+    assert_eq!(justify(JustifyBiasRight, "a b c d", 9), "a b  c  d");
+}
+
+#[test]
+fn single_word() {
+    assert_eq!(justify(JustifyBiasLeft, "word", 7), "word   ");
+    assert_eq!(justify(JustifyBiasRight, "word", 7), "word   ");
+}
+
+#[test]
+fn edge_whitespaces_are_not_gaps() {
+    assert_eq!(justify(JustifyBiasLeft, " a  b ", 9), " a     b ");
+}
+
+#[test]
+fn exact_fit() {
+    assert_eq!(justify(JustifyBiasLeft, "a b", 3), "a b");
+}
+
+#[test]
+fn wide_characters() {
+    let actual = justify(JustifyBiasLeft, "\u{4E2D} \u{6587} x", 10);
+    assert_eq!(actual, "\u{4E2D}   \u{6587}  x");
+    assert_eq!(actual.as_str().width(), 10);
+}
+
+#[test]
+fn ansi_escape_sequences() {
+    let padded_value = PaddedValue {
+        pad: JustifyBiasLeft,
+        value: AnsiUnicodeWidth::from("\u{1B}[1mab\u{1B}[0m cd"),
+        pad_block: ' ',
+        total_width: 8,
+        handle_excess: PanicOnExcess,
+    };
+    assert_eq!(padded_value.to_string(), "\u{1B}[1mab\u{1B}[0m    cd");
+}
+
+/// Value whose tags (such as `<b>`) are displayed but not measured.
+struct Markup(&'static str);
+
+impl core::fmt::Display for Markup {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(formatter, "{}", self.0)
+    }
+}
+
+impl Width for Markup {
+    fn width(&self) -> usize {
+        let mut width = 0;
+        self.for_each_char_width(&mut |_, character_width| {
+            width += character_width.unwrap_or(0);
+            Ok(())
+        })
+        .unwrap();
+        width
+    }
+
+    fn for_each_char_width(&self, callback: CharWidthCallback<'_>) -> core::fmt::Result {
+        let mut in_tag = false;
+        self.0.chars().try_for_each(|character| {
+            if character == '<' {
+                in_tag = true;
+            }
+            let width = if in_tag { None } else { Some(1) };
+            if character == '>' {
+                in_tag = false;
+            }
+            callback(character, width)
+        })
+    }
+}
+
+#[test]
+fn gaps_measured_by_value_width() {
+    let justify = |value| {
+        PaddedValue {
+            pad: JustifyBiasLeft,
+            value: Markup(value),
+            pad_block: '-',
+            total_width: 7,
+            handle_excess: PanicOnExcess,
+        }
+        .to_string()
+    };
+    assert_eq!(justify("<i class>a b</i>"), "<i class>a ----b</i>");
+    assert_eq!(justify("<b>a</b> <b>b c</b>"), "<b>a</b> <b>-b -c</b>");
+}
@@ -0,0 +1,203 @@
+#![cfg(feature = "std")]
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{
+    AlignCenterLeft, AlignLeft, AlignRight, Alignment, Ellipsize, LabeledColumn,
+    LabeledColumnBuilder, LabeledRow, TruncateEnd, Width, WidthConstraint,
+};
+
+fn render<Rows>(rows: Rows) -> Vec<String>
+where
+    Rows: IntoIterator,
+    Rows::Item: ToString,
+{
+    rows.into_iter().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn header_wider_than_values() {
+    let column = LabeledColumn {
+        values: ["1", "22"].iter(),
+        pad_block: ' ',
+        pad: AlignRight,
+        header: Some("Quantity"),
+        footer: None,
+        label_pad_block: ' ',
+        label_pad: AlignCenterLeft,
+        separator: Some('='),
+        width_constraint: WidthConstraint::default(),
+        handle_excess: TruncateEnd,
+    };
+    let expected = ["Quantity", "========", "       1", "      22"];
+    assert_eq!(render(column), expected);
+}
+
+#[test]
+fn values_wider_than_labels() {
+    let column = LabeledColumn {
+        values: ["JavaScript", "Go"].iter(),
+        pad_block: '.',
+        pad: AlignLeft,
+        header: Some("Lang"),
+        footer: Some("2"),
+        label_pad_block: '-',
+        label_pad: AlignRight,
+        separator: None::<char>,
+        width_constraint: WidthConstraint::default(),
+        handle_excess: TruncateEnd,
+    };
+    let expected = ["------Lang", "JavaScript", "Go........", "---------2"];
+    assert_eq!(render(column), expected);
+}
+
+#[test]
+fn footer_only() {
+    let column = LabeledColumn {
+        values: ["3", "4"].iter(),
+        pad_block: ' ',
+        pad: AlignRight,
+        header: None,
+        footer: Some("total 7"),
+        label_pad_block: ' ',
+        label_pad: AlignRight,
+        separator: Some('-'),
+        width_constraint: WidthConstraint::default(),
+        handle_excess: TruncateEnd,
+    };
+    let expected = ["      3", "      4", "-------", "total 7"];
+    assert_eq!(render(column), expected);
+}
+
+#[test]
+fn no_labels() {
+    let column = LabeledColumn {
+        values: ["a", "bbb"].iter(),
+        pad_block: ' ',
+        pad: AlignRight,
+        header: None::<&str>,
+        footer: None,
+        label_pad_block: ' ',
+        label_pad: AlignRight,
+        separator: Some('-'),
+        width_constraint: WidthConstraint::default(),
+        handle_excess: TruncateEnd,
+    };
+    assert_eq!(render(column), ["  a", "bbb"]);
+}
+
+#[test]
+fn constrained_width() {
+    let column = LabeledColumn {
+        values: ["1", "123456"].iter(),
+        pad_block: ' ',
+        pad: AlignRight,
+        header: Some("Quantity"),
+        footer: Some("n"),
+        label_pad_block: ' ',
+        label_pad: AlignCenterLeft,
+        separator: Some('-'),
+        width_constraint: WidthConstraint {
+            min_width: 0,
+            max_width: Some(4),
+            fixed_width: None,
+        },
+        handle_excess: Ellipsize::default(),
+    };
+    let expected = ["Qua\u{2026}", "----", "   1", "123\u{2026}", "----", " n  "];
+    assert_eq!(render(column), expected);
+}
+
+#[test]
+fn min_width_and_labels() {
+    let column = LabeledColumn {
+        values: ["a"].iter(),
+        pad_block: '.',
+        pad: AlignLeft,
+        header: Some("ab"),
+        footer: None,
+        label_pad_block: ' ',
+        label_pad: AlignLeft,
+        separator: None::<char>,
+        width_constraint: WidthConstraint {
+            min_width: 3,
+            ..WidthConstraint::default()
+        },
+        handle_excess: TruncateEnd,
+    };
+    assert_eq!(render(column), ["ab ", "a.."]);
+}
+
+#[test]
+fn wide_characters() {
+    let column = LabeledColumn {
+        values: ["a", "bb"].iter(),
+        pad_block: ' ',
+        pad: AlignLeft,
+        header: Some("\u{540D}\u{524D}"),
+        footer: None,
+        label_pad_block: ' ',
+        label_pad: AlignLeft,
+        separator: Some("=-"),
+        width_constraint: WidthConstraint::default(),
+        handle_excess: TruncateEnd,
+    };
+    let expected = ["\u{540D}\u{524D}", "=-=-", "a   ", "bb  "];
+    assert_eq!(render(column), expected);
+}
+
+#[test]
+fn rows_and_len() {
+    let mut iter = LabeledColumn {
+        values: ["a", "bb", "c"].iter(),
+        pad_block: ' ',
+        pad: Alignment::Right,
+        header: Some("h"),
+        footer: Some("f"),
+        label_pad_block: ' ',
+        label_pad: Alignment::Left,
+        separator: Some('-'),
+        width_constraint: WidthConstraint::default(),
+        handle_excess: TruncateEnd,
+    }
+    .into_iter();
+    assert_eq!(iter.total_width(), 2);
+    assert_eq!(iter.len(), 7);
+    let mut kinds = Vec::new();
+    while let Some(row) = iter.next() {
+        assert_eq!(row.width(), 2);
+        let kind = match row {
+            LabeledRow::Header(_) => 'h',
+            LabeledRow::Separator(_) => 's',
+            LabeledRow::Value(_) => 'v',
+            LabeledRow::Footer(_) => 'f',
+        };
+        kinds.push((kind, iter.len()));
+    }
+    let expected = [
+        ('h', 6),
+        ('s', 5),
+        ('v', 4),
+        ('v', 3),
+        ('v', 2),
+        ('s', 1),
+        ('f', 0),
+    ];
+    assert_eq!(kinds, expected);
+}
+
+#[test]
+fn builder() {
+    let column = LabeledColumnBuilder::default()
+        .values([10, 200].iter())
+        .pad_block(' ')
+        .pad(AlignRight)
+        .header(Some("n"))
+        .footer(None)
+        .label_pad_block(' ')
+        .label_pad(AlignCenterLeft)
+        .separator(Some('-'))
+        .width_constraint(WidthConstraint::default())
+        .handle_excess(TruncateEnd)
+        .build()
+        .unwrap();
+    assert_eq!(render(column), [" n ", "---", " 10", "200"]);
+}
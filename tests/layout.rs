@@ -0,0 +1,281 @@
+#![cfg(feature = "std")]
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{
+    AlignLeft, Alignment, AnsiUnicodeWidth, ColumnLayout, LayoutTable, LayoutTableBuilder,
+    ShrinkPolicy, TableLayout,
+};
+
+fn rows() -> Vec<Vec<&'static str>> {
+    vec![
+        vec!["id", "title", "status"],
+        vec!["1", "Fix the parser", "open"],
+        vec!["22", "Wrap long lines in tables", "closed"],
+    ]
+}
+
+fn layout(shrink: ShrinkPolicy, target_width: usize) -> TableLayout {
+    TableLayout {
+        columns: vec![
+            ColumnLayout {
+                pad: Alignment::Right,
+                priority: 9,
+                ..ColumnLayout::default()
+            },
+            ColumnLayout {
+                min_width: 5,
+                shrink,
+                ..ColumnLayout::default()
+            },
+            ColumnLayout {
+                priority: 9,
+                ..ColumnLayout::default()
+            },
+        ],
+        separator: " ",
+        target_width,
+    }
+}
+
+fn render(layout: TableLayout) -> Vec<String> {
+    let rows = rows();
+    LayoutTable {
+        rows: rows.iter(),
+        pad_block: ' ',
+        layout,
+    }
+    .into_iter()
+    .map(|x| x.to_string())
+    .collect()
+}
+
+#[test]
+fn fits_without_shrinking() {
+    let expected = [
+        "id title                     status",
+        " 1 Fix the parser            open  ",
+        "22 Wrap long lines in tables closed",
+    ];
+    assert_eq!(render(layout(ShrinkPolicy::Wrap, 35)), expected);
+    assert_eq!(render(layout(ShrinkPolicy::Wrap, 100)), expected);
+}
+
+#[test]
+fn measured_by_value_width() {
+    let rows = vec![
+        vec![
+            AnsiUnicodeWidth::from("\u{1B}[1mname\u{1B}[0m"),
+            AnsiUnicodeWidth::from("\u{1B}[32mok\u{1B}[0m"),
+        ],
+        vec![
+            AnsiUnicodeWidth::from("x"),
+            AnsiUnicodeWidth::from("\u{1B}[31mfailed badly\u{1B}[0m"),
+        ],
+    ];
+    let layout: TableLayout = TableLayout {
+        columns: vec![
+            ColumnLayout::default(),
+            ColumnLayout {
+                shrink: ShrinkPolicy::Wrap,
+                ..ColumnLayout::default()
+            },
+        ],
+        separator: " ",
+        target_width: 12,
+    };
+    let iter = LayoutTable {
+        rows: rows.iter(),
+        pad_block: ' ',
+        layout,
+    }
+    .into_iter();
+    assert_eq!(iter.natural_widths(), &[4, 12]);
+    assert_eq!(iter.column_widths(), &[4, 7]);
+    let actual: Vec<_> = iter.map(|x| x.to_string()).collect();
+    let expected = [
+        "\u{1B}[1mname\u{1B}[0m \u{1B}[32mok\u{1B}[0m     ",
+        "x    \u{1B}[31mfailed \n     badly\u{1B}[0m  ",
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn truncate() {
+    let expected = [
+        "id title       status",
+        " 1 Fix the par open  ",
+        "22 Wrap long l closed",
+    ];
+    assert_eq!(render(layout(ShrinkPolicy::Truncate, 21)), expected);
+}
+
+#[test]
+fn ellipsize() {
+    let expected = [
+        "id title       status",
+        " 1 Fix the pa… open  ",
+        "22 Wrap long … closed",
+    ];
+    assert_eq!(render(layout(ShrinkPolicy::Ellipsize, 21)), expected);
+}
+
+#[test]
+fn wrap() {
+    let expected = [
+        "id title       status",
+        " 1 Fix the     open  \n   parser            ",
+        "22 Wrap long   closed\n   lines in          \n   tables            ",
+    ];
+    assert_eq!(render(layout(ShrinkPolicy::Wrap, 21)), expected);
+}
+
+#[test]
+fn shrink_by_priority() {
+    let layout = layout(ShrinkPolicy::Truncate, 10);
+    assert_eq!(layout.solve(&[2, 25, 6]), [2, 5, 1]);
+    assert_eq!(layout.solve(&[2, 3, 6]), [2, 3, 3]);
+    assert_eq!(layout.solve(&[2, 25, 6, 4]), [1, 5, 1, 1]);
+}
+
+#[test]
+fn shrink_huge_widths() {
+    let layout: TableLayout = TableLayout {
+        columns: vec![],
+        separator: "",
+        target_width: 10,
+    };
+    assert_eq!(layout.solve(&[5_000_000; 3]), [4, 3, 3]);
+    assert_eq!(layout.solve(&[usize::MAX / 4, 7, 2]), [4, 4, 2]);
+    assert_eq!(layout.solve(&[9, 9, 9, 9]), [3, 3, 2, 2]);
+}
+
+#[test]
+fn never_shrink_to_zero() {
+    let rows = [vec!["abc", "de"]];
+    let actual: Vec<_> = LayoutTable {
+        rows: rows.iter(),
+        pad_block: ' ',
+        layout: TableLayout {
+            columns: vec![
+                ColumnLayout {
+                    shrink: ShrinkPolicy::Wrap,
+                    ..ColumnLayout::<Alignment>::default()
+                },
+                ColumnLayout {
+                    shrink: ShrinkPolicy::Wrap,
+                    ..ColumnLayout::default()
+                },
+            ],
+            separator: "|",
+            target_width: 1,
+        },
+    }
+    .into_iter()
+    .map(|x| x.to_string())
+    .collect();
+    assert_eq!(actual, ["a|d\nb|e\nc| "]);
+}
+
+#[test]
+fn narrower_than_min_widths() {
+    let layout = TableLayout {
+        columns: vec![
+            ColumnLayout {
+                pad: AlignLeft,
+                min_width: 4,
+                ..ColumnLayout::default()
+            },
+            ColumnLayout {
+                pad: AlignLeft,
+                min_width: 4,
+                ..ColumnLayout::default()
+            },
+        ],
+        separator: " | ",
+        target_width: 5,
+    };
+    assert_eq!(layout.solve(&[10, 2]), [4, 2]);
+}
+
+#[test]
+fn iter_accessors() {
+    let rows = rows();
+    let mut iter = LayoutTable {
+        rows: rows.iter(),
+        pad_block: '.',
+        layout: layout(ShrinkPolicy::Wrap, 21),
+    }
+    .into_iter();
+    assert_eq!(iter.natural_widths(), &[2, 25, 6]);
+    assert_eq!(iter.column_widths(), &[2, 11, 6]);
+    assert_eq!(iter.total_width(), 21);
+    assert_eq!(iter.pad_block(), '.');
+    assert_eq!(iter.layout().target_width, 21);
+    assert_eq!(iter.len(), 3);
+    let line_counts: Vec<_> = iter.by_ref().map(|row| row.line_count()).collect();
+    assert_eq!(line_counts, [1, 2, 3]);
+    assert_eq!(iter.len(), 0);
+}
+
+#[test]
+fn wide_characters() {
+    let rows = [vec![
+        "\u{540D}\u{524D}",
+        "\u{8AAC}\u{660E}\u{6587}\u{3067}\u{3059}",
+    ]];
+    let actual: Vec<_> = LayoutTable {
+        rows: rows.iter(),
+        pad_block: ' ',
+        layout: TableLayout {
+            columns: vec![
+                ColumnLayout::<Alignment>::default(),
+                ColumnLayout {
+                    shrink: ShrinkPolicy::Wrap,
+                    ..ColumnLayout::default()
+                },
+            ],
+            separator: "|",
+            target_width: 10,
+        },
+    }
+    .into_iter()
+    .map(|x| x.to_string())
+    .collect();
+    let expected = ["\u{540D}\u{524D}|\u{8AAC}\u{660E} \n    |\u{6587}\u{3067} \n    |\u{3059}   "];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn ragged_rows() {
+    let rows = [vec!["a", "b", "c"], vec!["dddd"]];
+    let actual: Vec<_> = LayoutTable {
+        rows: rows.iter(),
+        pad_block: '-',
+        layout: TableLayout {
+            columns: vec![ColumnLayout {
+                shrink: ShrinkPolicy::Ellipsize,
+                ..ColumnLayout::<Alignment>::default()
+            }],
+            separator: " ",
+            target_width: 7,
+        },
+    }
+    .into_iter()
+    .map(|x| x.to_string())
+    .collect();
+    assert_eq!(actual, ["a-- b c", "dd…"]);
+}
+
+#[test]
+fn builder() {
+    let rows = rows();
+    let actual: Vec<_> = LayoutTableBuilder::default()
+        .rows(rows.iter())
+        .pad_block(' ')
+        .layout(layout(ShrinkPolicy::Truncate, 21))
+        .build()
+        .unwrap()
+        .into_iter()
+        .map(|x| x.to_string())
+        .collect();
+    assert_eq!(actual[1], " 1 Fix the par open  ");
+}
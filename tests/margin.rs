@@ -0,0 +1,76 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{
+    AlignLeft, AlignRight, Alignment, Margin, PaddedValue, PanicOnExcess, SidedPadBlock,
+    TruncateEnd,
+};
+
+#[test]
+fn margin_with_alignment() {
+    let padded_value = PaddedValue {
+        pad: Margin {
+            left: 1,
+            right: 2,
+            inner: Alignment::Right,
+        },
+        value: "abc",
+        pad_block: '-',
+        total_width: 9,
+        handle_excess: PanicOnExcess,
+    };
+    assert_eq!(padded_value.to_string(), "----abc--");
+}
+
+#[test]
+fn margin_wider_than_pad() {
+    let padded_value = |total_width| {
+        PaddedValue {
+            pad: Margin {
+                left: 2,
+                right: 2,
+                inner: AlignRight,
+            },
+            value: "abc",
+            pad_block: SidedPadBlock {
+                left: '>',
+                right: '<',
+            },
+            total_width,
+            handle_excess: PanicOnExcess,
+        }
+        .to_string()
+    };
+    assert_eq!(padded_value(3), "abc");
+    assert_eq!(padded_value(4), ">abc");
+    assert_eq!(padded_value(6), ">>abc<");
+    assert_eq!(padded_value(8), ">>>abc<<");
+}
+
+#[test]
+fn sided_blocks_with_truncation() {
+    let padded_value = PaddedValue {
+        pad: AlignLeft,
+        value: "a\u{4E2D}",
+        pad_block: SidedPadBlock {
+            left: '>',
+            right: '<',
+        },
+        total_width: 2,
+        handle_excess: TruncateEnd,
+    };
+    assert_eq!(padded_value.to_string(), "a>");
+}
+
+#[test]
+fn sided_blocks_fill_right() {
+    let padded_value = PaddedValue {
+        pad: AlignLeft,
+        value: "abc",
+        pad_block: SidedPadBlock {
+            left: '>',
+            right: "<>",
+        },
+        total_width: 8,
+        handle_excess: PanicOnExcess,
+    };
+    assert_eq!(padded_value.to_string(), "abc<><> ");
+}
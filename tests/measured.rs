@@ -0,0 +1,66 @@
+use core::fmt::{Display, Error, Formatter};
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{align_left, align_right, MeasuredDisplay, Width};
+
+struct Chunks(&'static [&'static str]);
+
+impl Display for Chunks {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result<(), Error> {
+        for chunk in self.0 {
+            write!(formatter, "{}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn measured_display() {
+    let value = MeasuredDisplay::from(Chunks(&["ab", "\u{4F60}\u{597D}", "", "c"]));
+    assert_eq!(value.width(), 7);
+    assert_eq!(align_right(value, 9).to_string(), "  ab\u{4F60}\u{597D}c");
+}
+
+#[test]
+fn measured_display_into_inner() {
+    let value = MeasuredDisplay::from(123);
+    assert_eq!(*value.as_inner(), 123);
+    assert_eq!(value.into_inner(), 123);
+}
+
+#[test]
+fn arguments() {
+    let name = "\u{4F60}";
+    let actual = align_left(format_args!("<{}:{}>", name, 42), 10).to_string();
+    assert_eq!(actual, "<\u{4F60}:42>   ");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn smart_pointers() {
+    use std::{borrow::Cow, rc::Rc, sync::Arc};
+    use zero_copy_pads::align_center_left;
+    let boxed: Box<str> = "\u{4F60}\u{597D}".into();
+    let rc: Rc<str> = "abc".into();
+    let arc: Arc<str> = "ab".into();
+    let borrowed: Cow<str> = Cow::Borrowed("a");
+    let owned: Cow<str> = Cow::Owned("abcd".to_string());
+    assert_eq!(boxed.width(), 4);
+    assert_eq!(rc.width(), 3);
+    assert_eq!(arc.width(), 2);
+    assert_eq!(borrowed.width(), 1);
+    assert_eq!(owned.width(), 4);
+    assert_eq!(align_right(boxed, 6).to_string(), "  \u{4F60}\u{597D}");
+    assert_eq!(align_center_left(arc, 6).to_string(), "  ab  ");
+    assert_eq!(align_left(owned, 6).to_string(), "abcd  ");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn path_display() {
+    let path = std::path::Path::new("dir/\u{6587}\u{4EF6}.txt");
+    assert_eq!(path.display().width(), 12);
+    assert_eq!(
+        align_right(path.display(), 14).to_string(),
+        "  dir/\u{6587}\u{4EF6}.txt",
+    );
+}
@@ -0,0 +1,81 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{align_left, align_right, Precision, Width};
+
+#[test]
+fn unsigned_integers() {
+    assert_eq!(0u8.width(), 1);
+    assert_eq!(9u8.width(), 1);
+    assert_eq!(10u8.width(), 2);
+    assert_eq!(u8::MAX.width(), 3);
+    assert_eq!(u16::MAX.width(), 5);
+    assert_eq!(u32::MAX.width(), 10);
+    assert_eq!(u64::MAX.width(), 20);
+    assert_eq!(u128::MAX.width(), 39);
+    assert_eq!(usize::MAX.width(), usize::MAX.to_string().len());
+}
+
+#[test]
+fn signed_integers() {
+    assert_eq!(0i32.width(), 1);
+    assert_eq!((-1i32).width(), 2);
+    assert_eq!((-10i32).width(), 3);
+    assert_eq!(i8::MIN.width(), 4);
+    assert_eq!(i8::MAX.width(), 3);
+    assert_eq!(i64::MIN.width(), 20);
+    assert_eq!(i128::MIN.width(), 40);
+    assert_eq!(isize::MIN.width(), isize::MIN.to_string().len());
+}
+
+#[test]
+fn integers_match_display() {
+    for value in (-1000i64..=1000).chain([i64::MIN, i64::MAX, 99_999, 100_000].iter().copied()) {
+        assert_eq!(value.width(), value.to_string().len(), "value: {}", value);
+    }
+}
+
+#[test]
+fn chars_and_bools() {
+    assert_eq!('a'.width(), 1);
+    assert_eq!('\u{4F60}'.width(), 2);
+    assert_eq!('\u{0}'.width(), 0);
+    assert_eq!(true.width(), 4);
+    assert_eq!(false.width(), 5);
+}
+
+#[test]
+fn floats() {
+    assert_eq!(1.5f64.width(), 3);
+    assert_eq!((-0.25f32).width(), 5);
+    assert_eq!(1e21f64.width(), 1e21f64.to_string().len());
+    assert_eq!(f64::NAN.width(), 3);
+    assert_eq!(f64::NEG_INFINITY.width(), 4);
+}
+
+#[test]
+fn precision() {
+    let precision = |value: f64, precision: usize| Precision { value, precision };
+    assert_eq!(precision(1.23456, 0).to_string(), "1");
+    assert_eq!(precision(1.23456, 3).width(), 5);
+    assert_eq!(precision(-9.999, 2).to_string(), "-10.00");
+    assert_eq!(precision(-9.999, 2).width(), 6);
+    assert_eq!(precision(f64::INFINITY, 2).width(), 3);
+}
+
+#[test]
+fn pad_numbers() {
+    assert_eq!(align_right(42u64, 8).to_string(), "      42");
+    assert_eq!(align_left(-7i8, 4).to_string(), "-7  ");
+    assert_eq!(align_right('\u{4F60}', 4).to_string(), "  \u{4F60}");
+    assert_eq!(align_left(true, 6).to_string(), "true  ");
+    assert_eq!(
+        align_right(
+            Precision {
+                value: 2.5f32,
+                precision: 2
+            },
+            6
+        )
+        .to_string(),
+        "  2.50",
+    );
+}
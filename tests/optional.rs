@@ -0,0 +1,48 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{align_center_left, align_left, OptionalValue, Width};
+
+#[test]
+fn some_and_none() {
+    let values = [Some("\u{4F60}\u{597D}"), None, Some("ab")];
+    let actual: Vec<_> = values
+        .iter()
+        .map(|value| {
+            let value = OptionalValue {
+                value: *value,
+                placeholder: "n/a",
+            };
+            (value.width(), align_left(value, 5).to_string())
+        })
+        .collect();
+    assert_eq!(
+        actual,
+        [
+            (4, "\u{4F60}\u{597D} ".to_string()),
+            (3, "n/a  ".to_string()),
+            (2, "ab   ".to_string())
+        ],
+    );
+}
+
+#[test]
+fn wide_placeholder() {
+    let value = OptionalValue {
+        value: None::<u8>,
+        placeholder: '\u{2014}',
+    };
+    assert_eq!(value.width(), 1);
+    assert_eq!(align_center_left(value, 3).to_string(), " \u{2014} ");
+    let value = OptionalValue {
+        value: None::<u8>,
+        placeholder: "\u{7A7A}",
+    };
+    assert_eq!(value.width(), 2);
+    assert_eq!(value.to_string(), "\u{7A7A}");
+}
+
+#[test]
+fn default() {
+    let value: OptionalValue<u32> = OptionalValue::default();
+    assert_eq!(value.to_string(), "");
+    assert_eq!(value.width(), 0);
+}
@@ -0,0 +1,108 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{pad_args, pad_write};
+
+#[test]
+fn named() {
+    let actual =
+        pad_args!("{name:<8}|{age:>4}", name = "\u{4F60}\u{597D}", age = "30",).to_string();
+    assert_eq!(actual, "\u{4F60}\u{597D}    |  30");
+}
+
+#[test]
+fn positional() {
+    let actual = pad_args!("[{:^5}][{}][{:>3}]", "a", "b", "c").to_string();
+    assert_eq!(actual, "[  a  ][b][  c]");
+}
+
+#[test]
+fn indexed() {
+    let actual = pad_args!("{1:>3}{0:<3}{1}", "a", "b").to_string();
+    assert_eq!(actual, "  ba  b");
+}
+
+#[test]
+fn mixed() {
+    let actual = pad_args!("{:>4}:{value:<.6}", "key", value = "\u{1F600}").to_string();
+    assert_eq!(actual, " key:\u{1F600}....");
+}
+
+#[test]
+fn escapes() {
+    let actual = pad_args!("{{{:>3}}}", "x").to_string();
+    assert_eq!(actual, "{  x}");
+}
+
+#[test]
+fn excess_policy() {
+    let actual = pad_args!("{:5>}|{:5.}", "abcdefg", "abcdefg").to_string();
+    assert_eq!(actual, "abcde|abcd…");
+}
+
+#[test]
+fn no_arguments() {
+    assert_eq!(pad_args!("plain {{text}}").to_string(), "plain {text}");
+}
+
+#[test]
+fn wide_widths() {
+    assert_eq!(
+        pad_args!("{name:<20}|", name = "x").to_string(),
+        "x                   |",
+    );
+    assert_eq!(pad_args!("{:>10}", "x").to_string(), "         x");
+    assert_eq!(
+        pad_args!("{:^12}|{:>08}", "ab", "42").to_string(),
+        "     ab     |      42"
+    );
+}
+
+#[test]
+fn excess_error() {
+    use core::fmt::Write;
+    let mut output = String::new();
+    assert!(write!(output, "{}", pad_args!("{:3?}", "abcd")).is_err());
+}
+
+#[test]
+fn repeated_arguments() {
+    let actual = pad_args!("{0:>3}|{a:<3}|{0}{a}", "x", a = "y").to_string();
+    assert_eq!(actual, "  x|y  |xy");
+}
+
+#[test]
+fn multi_byte_fill() {
+    let actual = pad_args!("{:^\u{2500}6}|{:>\u{3000}4}", "ab", "c").to_string();
+    assert_eq!(actual, "\u{2500}\u{2500}ab\u{2500}\u{2500}|\u{3000} c");
+}
+
+#[test]
+fn escaped_fill() {
+    let actual = pad_args!("{:>\\05}|{:<\\\\3}", 42, "a").to_string();
+    assert_eq!(actual, "00042|a\\\\");
+}
+
+#[test]
+fn write_to_string() {
+    use core::fmt::Write;
+    let mut output = String::new();
+    pad_write!(output, "{:>3}|", "a").unwrap();
+    pad_write!(output, "{:<3}|", "b").unwrap();
+    assert_eq!(output, "  a|b  |");
+}
+
+#[test]
+fn write_to_io() {
+    use std::io::Write;
+    let mut output = Vec::new();
+    pad_write!(output, "{:^5}", "\u{4F60}").unwrap();
+    assert_eq!(output, " \u{4F60}  ".as_bytes());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn format() {
+    use zero_copy_pads::pad_format;
+    assert_eq!(pad_format!("{x:~-6}", x = "ab"), "--ab--");
+    assert_eq!(pad_format!("{x:~-7}", x = "ab"), "---ab--");
+    assert_eq!(pad_format!("{{{}}}", "ab"), "{ab}");
+}
@@ -0,0 +1,93 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{
+    AlignCenterLeft, AlignCenterRight, AlignLeft, AlignRight, PadBlockWithFallback, PaddedValue,
+    PanicOnExcess, TruncateEnd, Width,
+};
+
+macro_rules! padded {
+    ($value:expr, $pad_block:expr, $total_width:expr, $pad:expr) => {
+        PaddedValue {
+            value: $value,
+            pad_block: $pad_block,
+            total_width: $total_width,
+            pad: $pad,
+            handle_excess: PanicOnExcess,
+        }
+        .to_string()
+    };
+}
+
+#[test]
+fn multi_character_block() {
+    assert_eq!(padded!("abc", "·-", 9, AlignLeft), "abc·-·-·-");
+    assert_eq!(padded!("abc", "·-", 8, AlignLeft), "abc·-·- ");
+    assert_eq!(padded!("abc", "·-", 8, AlignRight), "·-·- abc");
+}
+
+#[test]
+fn wide_character_block() {
+    let actual = padded!("abc", '\u{3000}', 8, AlignRight);
+    assert_eq!(actual, "\u{3000}\u{3000} abc");
+    assert_eq!(actual.as_str().width(), 8);
+}
+
+#[test]
+fn centered_with_wide_block() {
+    assert_eq!(
+        padded!("ab", '\u{3000}', 8, AlignCenterLeft),
+        "\u{3000} ab\u{3000} ",
+    );
+    assert_eq!(
+        padded!("ab", '\u{3000}', 9, AlignCenterRight),
+        "\u{3000}\u{3000}ab\u{3000} ",
+    );
+}
+
+#[test]
+fn custom_fallback() {
+    let pad_block = PadBlockWithFallback {
+        block: ". ",
+        fallback: '.',
+    };
+    assert_eq!(
+        padded!("Chapter 1", pad_block, 16, AlignLeft),
+        "Chapter 1. . . ."
+    );
+    assert_eq!(
+        padded!("Chapter 10", pad_block, 16, AlignLeft),
+        "Chapter 10. . . "
+    );
+    assert_eq!(
+        padded!("Chapter 100", pad_block, 16, AlignLeft),
+        "Chapter 100. . ."
+    );
+}
+
+#[test]
+fn nested_fallback() {
+    let pad_block = PadBlockWithFallback {
+        block: "<-->",
+        fallback: PadBlockWithFallback {
+            block: "=",
+            fallback: ' ',
+        },
+    };
+    assert_eq!(padded!("", pad_block, 11, AlignLeft), "<--><-->===");
+}
+
+#[test]
+fn zero_width_block() {
+    assert_eq!(padded!("abc", "", 5, AlignLeft), "abc  ");
+}
+
+#[test]
+fn truncation_with_wide_block() {
+    let padded_value = PaddedValue {
+        value: "a\u{4E2D}\u{6587}",
+        pad_block: '\u{3000}',
+        total_width: 2,
+        pad: AlignLeft,
+        handle_excess: TruncateEnd,
+    };
+    assert_eq!(padded_value.to_string(), "a ");
+}
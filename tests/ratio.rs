@@ -0,0 +1,122 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{
+    AlignCenterLeft, AlignCenterRight, AlignRatio, Pad, PaddedValue, PanicOnExcess, Rounding,
+};
+
+fn pad<Align: Pad<&'static str, char>>(
+    pad: Align,
+    value: &'static str,
+    total_width: usize,
+) -> String {
+    PaddedValue {
+        pad,
+        value,
+        pad_block: '-',
+        total_width,
+        handle_excess: PanicOnExcess,
+    }
+    .to_string()
+}
+
+fn ratio(numerator: usize, denominator: usize, rounding: Rounding) -> AlignRatio {
+    AlignRatio {
+        numerator,
+        denominator,
+        rounding,
+    }
+}
+
+#[test]
+fn equivalent_to_center() {
+    for total_width in 3..12 {
+        assert_eq!(
+            pad(ratio(1, 2, Rounding::Down), "abc", total_width),
+            pad(AlignCenterLeft, "abc", total_width),
+        );
+        assert_eq!(
+            pad(ratio(1, 2, Rounding::Up), "abc", total_width),
+            pad(AlignCenterRight, "abc", total_width),
+        );
+    }
+}
+
+#[test]
+fn rounding() {
+    // 10 pad blocks, 1/4 of them is 2.5
+    assert_eq!(pad(ratio(1, 4, Rounding::Down), "ab", 12), "--ab--------");
+    assert_eq!(pad(ratio(1, 4, Rounding::Up), "ab", 12), "---ab-------");
+    assert_eq!(
+        pad(ratio(1, 4, Rounding::HalfDown), "ab", 12),
+        "--ab--------"
+    );
+    assert_eq!(pad(ratio(1, 4, Rounding::HalfUp), "ab", 12), "---ab-------");
+    // 10 pad blocks, 1/3 of them is 3.33
+    assert_eq!(pad(ratio(1, 3, Rounding::HalfUp), "ab", 12), "---ab-------");
+    // 10 pad blocks, 2/3 of them is 6.67
+    assert_eq!(
+        pad(ratio(2, 3, Rounding::HalfDown), "ab", 12),
+        "-------ab---"
+    );
+}
+
+#[test]
+fn edge_ratios() {
+    assert_eq!(pad(ratio(0, 5, Rounding::Up), "ab", 5), "ab---");
+    assert_eq!(pad(ratio(5, 5, Rounding::Down), "ab", 5), "---ab");
+    assert_eq!(pad(ratio(7, 5, Rounding::Down), "ab", 5), "---ab");
+    assert_eq!(pad(ratio(1, 0, Rounding::Up), "ab", 5), "ab---");
+    assert_eq!(pad(AlignRatio::default(), "ab", 5), "ab---");
+}
+
+#[test]
+fn divide() {
+    assert_eq!(Rounding::Down.divide(7, 2), 3);
+    assert_eq!(Rounding::Up.divide(7, 2), 4);
+    assert_eq!(Rounding::HalfDown.divide(7, 2), 3);
+    assert_eq!(Rounding::HalfUp.divide(7, 2), 4);
+    assert_eq!(Rounding::HalfUp.divide(6, 2), 3);
+    assert_eq!(Rounding::Up.divide(6, 0), 0);
+}
+
+#[test]
+fn divide_near_max() {
+    let max = usize::MAX;
+    assert_eq!(Rounding::Down.divide(max, max - 1), 1);
+    assert_eq!(Rounding::HalfUp.divide(max - 1, max), 1);
+    assert_eq!(Rounding::HalfDown.divide(max - 1, max), 1);
+    assert_eq!(Rounding::HalfDown.divide(max / 2, max), 0);
+    assert_eq!(Rounding::HalfUp.divide(max / 2 + 1, max), 1);
+}
+
+/// Pad block that writes the width of each fill instead of the blocks.
+struct WidthOfFill;
+
+impl zero_copy_pads::PadBlock for WidthOfFill {
+    fn fill(&self, formatter: &mut core::fmt::Formatter<'_>, width: usize) -> core::fmt::Result {
+        write!(formatter, "[{}]", width)
+    }
+}
+
+#[test]
+fn huge_pad_width() {
+    let max = usize::MAX;
+    let pad = |align: AlignRatio| {
+        PaddedValue {
+            pad: align,
+            value: "",
+            pad_block: WidthOfFill,
+            total_width: max,
+            handle_excess: PanicOnExcess,
+        }
+        .to_string()
+    };
+    assert_eq!(
+        pad(ratio(1, 2, Rounding::Down)),
+        format!("[{}][{}]", max / 2, max - max / 2),
+    );
+    assert_eq!(
+        pad(ratio(max - 1, max, Rounding::Up)),
+        format!("[{}][1]", max - 1),
+    );
+    assert_eq!(pad(ratio(max, max, Rounding::Up)), format!("[{}][0]", max));
+}
@@ -0,0 +1,133 @@
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{Alignment, ExcessPolicy, FormatSpec, ParseFormatSpecError};
+
+fn spec(
+    alignment: Alignment,
+    pad_block: char,
+    total_width: usize,
+    excess_policy: ExcessPolicy,
+) -> FormatSpec {
+    FormatSpec {
+        alignment,
+        pad_block,
+        total_width,
+        excess_policy,
+    }
+}
+
+#[test]
+fn parse() {
+    use Alignment::*;
+    use ExcessPolicy::*;
+    let cases = [
+        ("20", spec(Left, ' ', 20, Ignore)),
+        ("^-20!", spec(CenterLeft, '-', 20, Panic)),
+        (">08", spec(Right, ' ', 8, Ignore)),
+        (">12", spec(Right, ' ', 12, Ignore)),
+        ("^20", spec(CenterLeft, ' ', 20, Ignore)),
+        ("=15", spec(JustifyBiasLeft, ' ', 15, Ignore)),
+        ("#100.", spec(JustifyBiasRight, ' ', 100, Ellipsize)),
+        ("<-20", spec(Left, '-', 20, Ignore)),
+        (">8", spec(Right, ' ', 8, Ignore)),
+        ("~\u{3000}10?", spec(CenterRight, '\u{3000}', 10, Error)),
+        ("=5<", spec(JustifyBiasLeft, ' ', 5, TruncateStart)),
+        ("#.5>", spec(JustifyBiasRight, '.', 5, TruncateEnd)),
+        ("<<3^", spec(Left, '<', 3, TruncateMiddle)),
+        ("7.", spec(Left, ' ', 7, Ellipsize)),
+        (">\\08", spec(Right, '0', 8, Ignore)),
+        ("^\\\\12", spec(CenterLeft, '\\', 12, Ignore)),
+        ("<\\5", spec(Left, '\\', 5, Ignore)),
+        ("~\\-4.", spec(CenterRight, '-', 4, Ellipsize)),
+    ];
+    for (text, expected) in cases.iter() {
+        assert_eq!(text.parse::<FormatSpec>(), Ok(*expected), "{:?}", text);
+    }
+}
+
+#[test]
+fn parse_errors() {
+    use ParseFormatSpecError::*;
+    let cases = [
+        ("", MissingWidth),
+        ("^-", MissingWidth),
+        ("^-!", MissingWidth),
+        ("x20", MissingWidth),
+        ("99999999999999999999999", WidthOverflow),
+        ("20x", UnexpectedCharacter('x')),
+        ("20!!", UnexpectedCharacter('!')),
+    ];
+    for (text, expected) in cases.iter() {
+        assert_eq!(text.parse::<FormatSpec>(), Err(*expected), "{:?}", text);
+    }
+    assert_eq!(
+        UnexpectedCharacter('x').to_string(),
+        "unexpected character 'x'",
+    );
+}
+
+#[test]
+fn display_round_trip() {
+    for text in &[
+        "<-20!", "> 8", "> 12", "^*20", "^ 5", "~*3.", "=_10<", "# 1>", "<x4?",
+    ] {
+        let spec: FormatSpec = text.parse().unwrap();
+        assert_eq!(spec.to_string(), *text);
+    }
+    assert_eq!("12".parse::<FormatSpec>().unwrap().to_string(), "< 12");
+}
+
+#[test]
+fn display_round_trip_all_policies() {
+    use ExcessPolicy::*;
+    let policies = [
+        Ignore,
+        Panic,
+        Error,
+        TruncateStart,
+        TruncateEnd,
+        TruncateMiddle,
+        Ellipsize,
+    ];
+    for &excess_policy in policies.iter() {
+        for &pad_block in &[' ', '-', '<', '.', '!', '\u{3000}'] {
+            let expected = spec(Alignment::CenterRight, pad_block, 12, excess_policy);
+            let text = expected.to_string();
+            assert_eq!(text.parse::<FormatSpec>(), Ok(expected), "{:?}", text);
+        }
+    }
+}
+
+#[test]
+fn display_escaped_fill() {
+    for fill in ['0', '7', '9', '\\'].iter() {
+        let expected = spec(Alignment::Right, *fill, 8, ExcessPolicy::Ignore);
+        let text = expected.to_string();
+        assert_eq!(text, format!(">\\{}8", fill));
+        assert_eq!(text.parse::<FormatSpec>(), Ok(expected), "{:?}", text);
+    }
+    let zero_fill: FormatSpec = ">\\08".parse().unwrap();
+    assert_eq!(zero_fill.pad("42").to_string(), "00000042");
+}
+
+#[test]
+fn pad() {
+    let pad = |text: &str, value: &'static str| {
+        text.parse::<FormatSpec>().unwrap().pad(value).to_string()
+    };
+    assert_eq!(pad(">08", "42"), "      42");
+    assert_eq!(pad(">12", "42"), "          42");
+    assert_eq!(pad("^20", "ab"), "         ab         ");
+    assert_eq!(pad(">*12", "42"), "**********42");
+    assert_eq!(pad("^-6", "\u{4F60}"), "--\u{4F60}--");
+    assert_eq!(pad("5>", "abcdefg"), "abcde");
+    assert_eq!(pad("5<", "abcdefg"), "cdefg");
+    assert_eq!(pad("5", "abcdefg"), "abcdefg");
+    assert_eq!(pad("=.9", "a b c"), "a ..b ..c");
+}
+
+#[test]
+#[should_panic(expected = "value's width (7) is greater than total_width (5)")]
+fn pad_panic_on_excess() {
+    let spec: FormatSpec = "5!".parse().unwrap();
+    let _ = spec.pad("abcdefg").to_string();
+}
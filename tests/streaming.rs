@@ -0,0 +1,132 @@
+#![cfg(feature = "std")]
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{AlignLeft, AlignRight, StreamingColumn, StreamingColumnBuilder};
+
+const VALUES: &[&str] = &["Rust", "C", "C++", "C#", "JavaScript", "Go"];
+
+fn render(window: usize) -> Vec<String> {
+    StreamingColumn {
+        values: VALUES.iter(),
+        pad_block: '-',
+        pad: AlignLeft,
+        window,
+    }
+    .into_iter()
+    .map(|x| x.to_string())
+    .collect()
+}
+
+#[test]
+fn window_larger_than_input() {
+    let expected: Vec<_> = VALUES.iter().map(|x| format!("{:-<10}", x)).collect();
+    assert_eq!(render(100), expected);
+    assert_eq!(render(VALUES.len()), expected);
+}
+
+#[test]
+fn sliding_window() {
+    let expected = [
+        "Rust",
+        "C---",
+        "C++-",
+        "C#--------",
+        "JavaScript",
+        "Go--------",
+    ];
+    assert_eq!(render(2), expected);
+}
+
+#[test]
+fn zero_window_is_one() {
+    let expected = ["Rust", "C---", "C++-", "C#--", "JavaScript", "Go--------"];
+    assert_eq!(render(0), expected);
+    assert_eq!(render(1), expected);
+}
+
+#[test]
+fn width_never_shrinks() {
+    let mut iter = StreamingColumn {
+        values: VALUES.iter(),
+        pad_block: ' ',
+        pad: AlignRight,
+        window: 2,
+    }
+    .into_iter();
+    assert_eq!(iter.total_width(), 0);
+    assert_eq!(iter.window(), 2);
+    let mut widths = Vec::new();
+    while let Some(value) = iter.next() {
+        widths.push((value.total_width, iter.total_width(), iter.buffered_len()));
+    }
+    let expected = [
+        (4, 4, 1),
+        (4, 4, 1),
+        (4, 4, 1),
+        (10, 10, 1),
+        (10, 10, 1),
+        (10, 10, 0),
+    ];
+    assert_eq!(widths, expected);
+}
+
+#[test]
+fn endless_input() {
+    let actual: Vec<_> = StreamingColumn {
+        values: (1..).map(|x| "x".repeat(x % 4)),
+        pad_block: '.',
+        pad: AlignRight,
+        window: 3,
+    }
+    .into_iter()
+    .take(5)
+    .map(|x| x.to_string())
+    .collect();
+    assert_eq!(actual, ["..x", ".xx", "xxx", "...", "..x"]);
+}
+
+#[test]
+fn reads_only_window_ahead() {
+    use std::cell::Cell;
+    let read = Cell::new(0);
+    let values = VALUES.iter().inspect(|_| read.set(read.get() + 1));
+    let mut iter = StreamingColumn {
+        values,
+        pad_block: ' ',
+        pad: AlignLeft,
+        window: 3,
+    }
+    .into_iter();
+    iter.next();
+    assert_eq!(read.get(), 3);
+    iter.next();
+    assert_eq!(read.get(), 4);
+}
+
+#[test]
+fn size_hint() {
+    let mut iter = StreamingColumn {
+        values: VALUES.iter(),
+        pad_block: ' ',
+        pad: AlignLeft,
+        window: 3,
+    }
+    .into_iter();
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+}
+
+#[test]
+fn builder() {
+    let actual: Vec<_> = StreamingColumnBuilder::default()
+        .values(VALUES.iter())
+        .pad_block(' ')
+        .pad(AlignRight)
+        .window(10)
+        .build()
+        .unwrap()
+        .into_iter()
+        .map(|x| x.to_string())
+        .collect();
+    assert_eq!(actual[0], "      Rust");
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "std")]
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{AlignCenterRight, AlignLeft, Alignment, PaddedTable, Width};
+
+const ROWS: &[&[&str]] = &[
+    &["Language", "Typing", "Year"],
+    &["Rust", "static", "2010"],
+    &["JavaScript", "dynamic", "1995"],
+    &["Go", "static", "2009"],
+];
+
+#[test]
+fn alignment_per_column() {
+    let padded_table = PaddedTable {
+        rows: ROWS.iter().map(|row| row.iter()),
+        pad_block: '-',
+        pads: vec![Alignment::Right, Alignment::CenterRight, Alignment::Left],
+        separator: " ",
+    };
+    let actual: Vec<_> = padded_table.into_iter().map(|x| x.to_string()).collect();
+    let expected = [
+        "--Language -Typing Year",
+        "------Rust -static 2010",
+        "JavaScript dynamic 1995",
+        "--------Go -static 2009",
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn pad_instance() {
+    let padded_table = PaddedTable {
+        rows: ROWS.iter().map(|row| row.iter()),
+        pad_block: '.',
+        pads: vec![AlignCenterRight, AlignCenterRight],
+        separator: "|",
+    };
+    let actual: Vec<_> = padded_table.into_iter().map(|x| x.to_string()).collect();
+    let expected = [
+        ".Language.|.Typing|Year",
+        "...Rust...|.static|2010",
+        "JavaScript|dynamic|1995",
+        "....Go....|.static|2009",
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn missing_pads_use_default() {
+    let padded_table = PaddedTable {
+        rows: ROWS.iter().map(|row| row.iter()),
+        pad_block: ' ',
+        pads: vec![Alignment::Right],
+        separator: " ",
+    };
+    let actual: Vec<_> = padded_table.into_iter().map(|x| x.to_string()).collect();
+    let expected = [
+        "  Language Typing  Year",
+        "      Rust static  2010",
+        "JavaScript dynamic 1995",
+        "        Go static  2009",
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn rows_of_different_lengths() {
+    let rows: &[&[&str]] = &[&["a", "bc", "def"], &["ghij"], &[], &["k", "l"]];
+    let padded_table = PaddedTable {
+        rows: rows.iter().map(|row| row.iter()),
+        pad_block: '-',
+        pads: vec![AlignLeft; 3],
+        separator: ":",
+    };
+    let iter = padded_table.into_iter();
+    assert_eq!(iter.column_widths(), &[4, 2, 3]);
+    assert_eq!(iter.total_width(), 11);
+    let actual: Vec<_> = iter.map(|x| (x.width(), x.to_string())).collect();
+    let expected = [
+        (11, "a---:bc:def".to_string()),
+        (4, "ghij".to_string()),
+        (0, "".to_string()),
+        (7, "k---:l-".to_string()),
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn len() {
+    let mut actual = Vec::new();
+    let mut iter = PaddedTable {
+        rows: ROWS.iter().map(|row| row.iter()),
+        pad_block: ' ',
+        pads: vec![Alignment::Left],
+        separator: " ",
+    }
+    .into_iter();
+    while let Some(row) = iter.next() {
+        actual.push((iter.len(), row.cells().len()));
+    }
+    assert_eq!(actual, [(3, 3), (2, 3), (1, 3), (0, 3)]);
+}
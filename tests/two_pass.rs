@@ -0,0 +1,91 @@
+use fmt_iter::FmtIter;
+use pipe_trait::Pipe;
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{AlignCenterRight, AlignLeft, Alignment, TwoPassColumn, Width};
+
+const VALUES: &[&str] = &["Rust", "C", "C++", "C#", "JavaScript", "Go"];
+
+#[test]
+fn align_left_with_len() {
+    let mut iter = TwoPassColumn {
+        values: VALUES.iter(),
+        pad_block: '-',
+        pad: AlignLeft,
+    }
+    .into_iter();
+    assert_eq!(iter.total_width(), 10);
+    let mut actual = Vec::new();
+    while let Some(value) = iter.next() {
+        actual.push((iter.len(), value.to_string()));
+    }
+    let expected = [
+        (5, "Rust------".to_string()),
+        (4, "C---------".to_string()),
+        (3, "C++-------".to_string()),
+        (2, "C#--------".to_string()),
+        (1, "JavaScript".to_string()),
+        (0, "Go--------".to_string()),
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn align_center_right() {
+    let actual: Vec<_> = TwoPassColumn {
+        values: VALUES.iter(),
+        pad_block: '-',
+        pad: AlignCenterRight,
+    }
+    .into_iter()
+    .map(|x| x.to_string())
+    .collect();
+    let expected = [
+        "---Rust---",
+        "-----C----",
+        "----C++---",
+        "----C#----",
+        "JavaScript",
+        "----Go----",
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn alignment_over_chained_iterator() {
+    let values = VALUES.iter().copied().filter(|x| x.len() < 5);
+    let actual: Vec<_> = TwoPassColumn {
+        values,
+        pad_block: '.',
+        pad: Alignment::Right,
+    }
+    .into_iter()
+    .map(|x| x.to_string())
+    .collect();
+    let expected = ["Rust", "...C", ".C++", "..C#", "..Go"];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn empty() {
+    let iter = TwoPassColumn {
+        values: [""; 0].iter(),
+        pad_block: ' ',
+        pad: AlignLeft,
+    }
+    .into_iter();
+    assert_eq!(iter.total_width(), 0);
+    assert_eq!(iter.count(), 0);
+}
+
+#[test]
+fn fmt_iter_width() {
+    let actual = TwoPassColumn {
+        values: VALUES.iter(),
+        pad_block: '-',
+        pad: AlignLeft,
+    }
+    .into_iter()
+    .pipe(FmtIter::from)
+    .width();
+    assert_eq!(actual, 10 * VALUES.len());
+}
@@ -1,5 +1,6 @@
+use core::fmt::{Display, Formatter, Result};
 use pretty_assertions::assert_eq;
-use zero_copy_pads::Width;
+use zero_copy_pads::{AlignRight, CharCount, PaddedValue, TruncateEnd, Width};
 
 #[test]
 fn width_of_str_indirect_references() {
@@ -19,3 +20,111 @@ fn width_owned_string_indirect_references() {
     assert_eq!(Width::width(&&&owned_string), 6);
     assert_eq!(Width::width(&&&&owned_string), 6);
 }
+
+#[test]
+fn width_of_ansi_colored_text() {
+    use zero_copy_pads::AnsiUnicodeWidth;
+    let width = |text: &str| AnsiUnicodeWidth::from(text).width();
+    assert_eq!(width("abcdef"), 6);
+    assert_eq!(width("\u{1B}[31mabc\u{1B}[0mdef"), 6);
+    assert_eq!(width("\u{1B}[1;38;5;208mabcdef\u{1B}[m"), 6);
+    assert_eq!(width("\u{9B}32mabcdef\u{9B}0m"), 6);
+    assert_eq!(width("\u{1B}(Babcdef"), 6);
+    assert_eq!(width("\u{1B}[31m中文\u{1B}[0m"), 4);
+}
+
+#[test]
+fn width_of_ansi_hyperlinks() {
+    use zero_copy_pads::AnsiUnicodeWidth;
+    let width = |text: &str| AnsiUnicodeWidth::from(text).width();
+    assert_eq!(
+        width("\u{1B}]8;;https://example.com\u{1B}\\link\u{1B}]8;;\u{1B}\\"),
+        4,
+    );
+    assert_eq!(
+        width("\u{1B}]8;;https://example.com\u{7}link\u{1B}]8;;\u{7}"),
+        4,
+    );
+    assert_eq!(width("\u{1B}]0;title\u{1B}[1mabc"), 3);
+}
+
+#[test]
+#[cfg(feature = "grapheme")]
+fn width_of_grapheme_clusters() {
+    use zero_copy_pads::GraphemeWidth;
+    let width = |text: &str| GraphemeWidth::from(text).width();
+    assert_eq!(width("abcdef"), 6);
+    assert_eq!(width("中文"), 4);
+    assert_eq!(width("e\u{301}"), 1);
+    assert_eq!(width("👍🏽"), 2);
+    assert_eq!(width("👩‍💻"), 2);
+    assert_eq!(width("👨‍👩‍👧‍👦"), 2);
+    assert_eq!(width("🇯🇵🇻🇳"), 4);
+    assert_eq!(width("❤️"), 2);
+    assert_eq!(width("1️⃣"), 2);
+    assert_eq!(width("☺︎"), 1);
+}
+
+/// Value that only overrides [`Width::width`], which counts every character as one column.
+struct OnlyWidth(&'static str);
+
+impl Display for OnlyWidth {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        write!(formatter, "{}", self.0)
+    }
+}
+
+impl Width for OnlyWidth {
+    fn width(&self) -> usize {
+        self.0.chars().count()
+    }
+}
+
+#[test]
+fn default_char_widths_follow_overridden_width() {
+    let value = OnlyWidth("\u{4E2D}\u{6587}ab");
+    assert_eq!(value.width(), 4);
+    let mut char_widths = Vec::new();
+    value
+        .for_each_char_width(&mut |character, width| {
+            char_widths.push((character, width));
+            Ok(())
+        })
+        .unwrap();
+    let expected = [
+        ('\u{4E2D}', Some(1)),
+        ('\u{6587}', Some(1)),
+        ('a', Some(1)),
+        ('b', Some(1)),
+    ];
+    assert_eq!(char_widths, expected);
+    let truncated = PaddedValue {
+        value,
+        pad_block: '-',
+        total_width: 3,
+        pad: AlignRight,
+        handle_excess: TruncateEnd,
+    };
+    assert_eq!(truncated.to_string(), "\u{4E2D}\u{6587}a");
+    let truncated = PaddedValue {
+        value: CharCount::from("\u{4E2D}\u{6587}ab"),
+        pad_block: '-',
+        total_width: 3,
+        pad: AlignRight,
+        handle_excess: TruncateEnd,
+    };
+    assert_eq!(truncated.to_string(), "\u{4E2D}\u{6587}a");
+}
+
+#[test]
+fn default_char_widths_of_unmeasured_text() {
+    let value = OnlyWidth("\u{200B}\u{200B}");
+    let mut char_widths = Vec::new();
+    value
+        .for_each_char_width(&mut |character, width| {
+            char_widths.push((character, width));
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(char_widths, [('\u{200B}', Some(0)), ('\u{200B}', Some(2))]);
+}
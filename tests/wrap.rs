@@ -0,0 +1,83 @@
+#![cfg(feature = "std")]
+use pretty_assertions::assert_eq;
+use zero_copy_pads::{AlignLeft, AlignRight, AnsiUnicodeWidth, WrappedValue};
+
+macro_rules! lines {
+    ($value:expr, $total_width:expr) => {
+        WrappedValue {
+            value: $value,
+            pad_block: '-',
+            total_width: $total_width,
+            pad: AlignLeft,
+        }
+        .lines()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+    };
+}
+
+#[test]
+fn fit_in_one_line() {
+    assert_eq!(lines!("abc def", 9), ["abc def--"]);
+    assert_eq!(lines!("abc def", 7), ["abc def"]);
+}
+
+#[test]
+fn break_at_spaces() {
+    assert_eq!(lines!("abc def ghi", 8), ["abc def-", "ghi-----"]);
+    assert_eq!(lines!("abc   def", 5), ["abc--", "def--"]);
+}
+
+#[test]
+fn break_after_hyphens() {
+    assert_eq!(lines!("zero-copy-pads", 10), ["zero-copy-", "pads------"]);
+}
+
+#[test]
+fn break_long_words() {
+    assert_eq!(lines!("abcdefghij", 4), ["abcd", "efgh", "ij--"]);
+    assert_eq!(lines!("ab cdefghij", 4), ["ab--", "cdef", "ghij"]);
+}
+
+#[test]
+fn break_between_wide_characters() {
+    assert_eq!(lines!("中文字符", 5), ["中文-", "字符-"]);
+    assert_eq!(lines!("中文字符", 1), ["-", "-", "-", "-"]);
+}
+
+#[test]
+fn mandatory_breaks() {
+    assert_eq!(lines!("abc\ndef", 9), ["abc------", "def------"]);
+    assert_eq!(lines!("abc\n\ndef\n", 4), ["abc-", "----", "def-"]);
+    assert_eq!(lines!("abc\r\ndef", 4), ["abc-", "def-"]);
+}
+
+#[test]
+fn measured_by_value_width() {
+    let colored = |text| AnsiUnicodeWidth::from(text);
+    assert_eq!(
+        lines!(colored("\u{1B}[31mabc\u{1B}[0m def ghi"), 7),
+        ["\u{1B}[31mabc\u{1B}[0m def", "ghi----"],
+    );
+    assert_eq!(
+        lines!(colored("\u{1B}[1mabcdef\u{1B}[0m"), 4),
+        ["\u{1B}[1mabcd", "ef\u{1B}[0m--"],
+    );
+}
+
+#[test]
+fn empty_value() {
+    assert_eq!(lines!("", 3), ["---"]);
+}
+
+#[test]
+fn display() {
+    let wrapped_value = WrappedValue {
+        value: "abc def ghi",
+        pad_block: ' ',
+        total_width: 8,
+        pad: AlignRight,
+    };
+    assert_eq!(wrapped_value.line_count(), 2);
+    assert_eq!(wrapped_value.to_string(), " abc def\n     ghi");
+}